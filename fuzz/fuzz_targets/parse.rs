@@ -0,0 +1,19 @@
+//! Parses arbitrary text as the input of a day, the first byte picking the
+//! day. Parsers have to return an error for malformed inputs, not panic.
+
+#![no_main]
+
+use adventofcode2022::runner;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, text)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(text) else {
+        return;
+    };
+
+    let day = selector % 14 + 1;
+    let _ = runner::find(day, 1).unwrap().parse(input);
+});
@@ -0,0 +1,19 @@
+//! Inputs that parse have to parse the same after going through `Display`.
+
+#![no_main]
+
+use adventofcode2022::{day11::Day11, day13::Input, solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(packet) = input.parse::<Input>() {
+        assert_eq!(packet.to_string().parse::<Input>().unwrap(), packet);
+    }
+
+    if let Ok(monkeys) = Day11::parse(input) {
+        let displayed: Vec<String> = monkeys.iter().map(|monkey| monkey.to_string()).collect();
+        let reparsed = Day11::parse(&displayed.join("\n")).unwrap();
+        let redisplayed: Vec<String> = reparsed.iter().map(|monkey| monkey.to_string()).collect();
+        assert_eq!(displayed, redisplayed);
+    }
+});
@@ -0,0 +1,307 @@
+//! Simulations of the days, and with the `viz` feature a player for them.
+
+#[cfg(feature = "viz")]
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "viz")]
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::{error::Error, *};
+
+/// A simulation that can be played one step at a time.
+pub trait Simulation {
+    /// Advances the simulation one step, `false` when it was already
+    /// finished.
+    fn step(&mut self) -> bool;
+
+    /// The current state drawn as text, one character per cell.
+    fn frame(&self) -> String;
+
+    /// A line describing the current state, shown below the frame.
+    fn caption(&self) -> String {
+        String::new()
+    }
+}
+
+/// The frame with its caption below it.
+#[cfg(feature = "viz")]
+fn framed(simulation: &dyn Simulation) -> String {
+    let frame = simulation.frame();
+    let caption = simulation.caption();
+    if caption.is_empty() {
+        frame.trim_end().to_string()
+    } else {
+        format!("{}\n\n{}", frame.trim_end(), caption)
+    }
+}
+
+type Builder = fn(&str, u8) -> Result<Box<dyn Simulation>, error::ParseError>;
+
+const SIMULATIONS: &[(u8, Builder)] = &[
+    #[cfg(feature = "day9")]
+    (9, day9::simulate),
+    #[cfg(feature = "day10")]
+    (10, day10::simulate),
+    #[cfg(feature = "day12")]
+    (12, day12::simulate),
+    #[cfg(feature = "day14")]
+    (14, day14::simulate),
+];
+
+/// The simulation of a part of the day, `None` if the day can't be
+/// animated.
+pub fn simulation(day: u8, part: u8, input: &str) -> Option<Result<Box<dyn Simulation>, Error>> {
+    SIMULATIONS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, builder)| builder(input, part).map_err(|source| Error::Parse { day, source }))
+}
+
+pub fn days() -> impl Iterator<Item = u8> {
+    SIMULATIONS.iter().map(|(day, _)| *day)
+}
+
+/// Plays simulations in the terminal, or just prints where they end up when
+/// stdout isn't a terminal.
+#[cfg(feature = "viz")]
+pub struct Animator {
+    interval: Duration,
+    steps_per_frame: usize,
+}
+
+/// The alternate screen in raw mode, restored when dropped so a panicking
+/// simulation doesn't leave the terminal broken.
+#[cfg(feature = "viz")]
+struct Screen;
+
+#[cfg(feature = "viz")]
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+
+        Ok(screen)
+    }
+}
+
+#[cfg(feature = "viz")]
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(feature = "viz")]
+impl Animator {
+    pub fn new(fps: u32, steps_per_frame: usize) -> Animator {
+        Animator {
+            interval: Duration::from_secs(1) / fps.max(1),
+            steps_per_frame: steps_per_frame.max(1),
+        }
+    }
+
+    /// Plays the simulation until it's finished and the user quits, then
+    /// prints its last frame.
+    pub fn play(&self, simulation: &mut dyn Simulation) -> io::Result<()> {
+        if !io::stdout().is_terminal() {
+            return self
+                .play_headless(simulation, &mut io::stdout())
+                .map(|_| ());
+        }
+
+        {
+            let _screen = Screen::enter()?;
+            self.play_interactive(simulation)?;
+        }
+
+        println!("{}", framed(simulation));
+        Ok(())
+    }
+
+    /// Runs the simulation to the end without drawing anything but the last
+    /// frame. Returns the number of steps taken.
+    pub fn play_headless(
+        &self,
+        simulation: &mut dyn Simulation,
+        out: &mut impl Write,
+    ) -> io::Result<usize> {
+        let mut steps = 0;
+        while simulation.step() {
+            steps += 1;
+        }
+
+        writeln!(out, "{}", framed(simulation))?;
+        Ok(steps)
+    }
+
+    /// Space pauses, `n` or the right arrow steps while paused, `+` and `-`
+    /// change the number of steps per frame and `q` quits.
+    fn play_interactive(&self, simulation: &mut dyn Simulation) -> io::Result<()> {
+        let mut out = io::stdout();
+        let mut steps_per_frame = self.steps_per_frame;
+        let mut paused = false;
+        let mut finished = false;
+        let mut steps = 0;
+
+        loop {
+            let state = if finished {
+                "finished"
+            } else if paused {
+                "paused"
+            } else {
+                "playing"
+            };
+            let status = format!(
+                "step {}, {} per frame, {}  [space] pause  [n] step  [+/-] speed  [q] quit",
+                steps, steps_per_frame, state
+            );
+            draw(&mut out, &framed(simulation), &status)?;
+
+            let deadline = Instant::now() + self.interval;
+            let mut single_step = false;
+            loop {
+                let timeout = if paused || finished {
+                    Duration::from_secs(1)
+                } else {
+                    deadline.saturating_duration_since(Instant::now())
+                };
+
+                if !event::poll(timeout)? {
+                    if paused || finished {
+                        continue;
+                    }
+                    break;
+                }
+
+                match event::read()? {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            return Ok(())
+                        }
+                        KeyCode::Char(' ') => {
+                            paused = !paused;
+                            break;
+                        }
+                        KeyCode::Char('n') | KeyCode::Right if paused => {
+                            single_step = true;
+                            break;
+                        }
+                        KeyCode::Char('+') | KeyCode::Char('=') => {
+                            steps_per_frame *= 2;
+                            break;
+                        }
+                        KeyCode::Char('-') => {
+                            steps_per_frame = (steps_per_frame / 2).max(1);
+                            break;
+                        }
+                        _ => {}
+                    },
+                    Event::Resize(_, _) => break,
+                    _ => {}
+                }
+            }
+
+            if finished || (paused && !single_step) {
+                continue;
+            }
+
+            let count = if single_step { 1 } else { steps_per_frame };
+            for _ in 0..count {
+                if !simulation.step() {
+                    finished = true;
+                    break;
+                }
+                steps += 1;
+            }
+        }
+    }
+}
+
+/// Draws the frame cropped to the terminal, with the status on the last row.
+#[cfg(feature = "viz")]
+fn draw(out: &mut impl Write, frame: &str, status: &str) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let rows = height.saturating_sub(1);
+
+    let mut row = 0;
+    for line in frame.lines().take(rows as usize) {
+        let line: String = line.chars().take(width as usize).collect();
+        queue!(
+            out,
+            cursor::MoveTo(0, row),
+            Print(line),
+            terminal::Clear(ClearType::UntilNewLine)
+        )?;
+        row += 1;
+    }
+
+    let status: String = status.chars().take(width as usize).collect();
+    queue!(
+        out,
+        cursor::MoveTo(0, row),
+        terminal::Clear(ClearType::FromCursorDown),
+        cursor::MoveTo(0, rows),
+        Print(status)
+    )?;
+    out.flush()
+}
+
+#[cfg(all(test, feature = "viz"))]
+mod tests {
+    use super::*;
+
+    struct Countdown(u32);
+
+    impl Simulation for Countdown {
+        fn step(&mut self) -> bool {
+            if self.0 == 0 {
+                return false;
+            }
+            self.0 -= 1;
+            true
+        }
+
+        fn frame(&self) -> String {
+            format!("{}\n", self.0)
+        }
+    }
+
+    #[test]
+    fn headless_prints_only_the_last_frame() {
+        let mut out = vec![];
+        let steps = Animator::new(30, 1)
+            .play_headless(&mut Countdown(5), &mut out)
+            .unwrap();
+
+        assert_eq!(steps, 5);
+        assert_eq!(String::from_utf8(out).unwrap(), "0\n");
+    }
+
+    #[test]
+    fn every_simulation_finishes_on_its_example() {
+        for day in days() {
+            for part in 1..=2 {
+                let input = input::read(day, part, &input::Source::Example).unwrap();
+                let mut simulation = simulation(day, part, &input).unwrap().unwrap();
+                let mut out = vec![];
+                Animator::new(30, 1)
+                    .play_headless(simulation.as_mut(), &mut out)
+                    .unwrap();
+
+                assert!(!out.is_empty(), "day {} part {}", day, part);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// A known-good answer for one part, tied to the exact input it was produced
+/// from.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// FNV-1a hash of the input, as produced by [`input_hash`].
+    pub input: String,
+    pub value: String,
+}
+
+/// Outcome of checking an answer against the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+/// The recorded answers, stored as an `answers.toml` file of `[[answer]]`
+/// tables.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    answers: Vec<Answer>,
+}
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Hashes an input with 64 bit FNV-1a, so answers are only compared against
+/// the input they were recorded for.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+impl Answers {
+    /// Loads the registry, a missing file being an empty registry.
+    pub fn load(path: &Path) -> Result<Answers, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(source) => {
+                return Err(Error::Io {
+                    path: Some(path.to_path_buf()),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&contents).map_err(|e| Error::InvalidFile {
+            path: path.to_path_buf(),
+            message: e.message().to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let contents = toml::to_string(self).map_err(|e| Error::InvalidFile {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+
+        fs::write(path, contents).map_err(|source| Error::Io {
+            path: Some(path.to_path_buf()),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&Answer> {
+        let hash = input_hash(input);
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input == hash)
+    }
+
+    pub fn verify(&self, day: u8, part: u8, input: &str, value: &str) -> Verdict {
+        match self.get(day, part, input) {
+            Some(answer) if answer.value == value => Verdict::Correct,
+            Some(answer) => Verdict::Wrong {
+                expected: answer.value.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Records an answer, replacing any previous one for the same input.
+    pub fn record(&mut self, day: u8, part: u8, input: &str, value: &str) {
+        let answer = Answer {
+            day,
+            part,
+            input: input_hash(input),
+            value: value.to_string(),
+        };
+
+        self.answers
+            .retain(|a| (a.day, a.part, &a.input) != (day, part, &answer.input));
+        self.answers.push(answer);
+        self.answers.sort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_recorded_answers() {
+        let mut answers = Answers::default();
+        answers.record(1, 1, "1000\n2000\n", "3000");
+
+        assert_eq!(
+            answers.verify(1, 1, "1000\n2000\n", "3000"),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.verify(1, 1, "1000\n2000\n", "2000"),
+            Verdict::Wrong {
+                expected: "3000".to_string()
+            }
+        );
+        assert_eq!(
+            answers.verify(1, 2, "1000\n2000\n", "3000"),
+            Verdict::Unknown
+        );
+        assert_eq!(answers.verify(1, 1, "1000\n", "3000"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn record_replaces_previous_answer() {
+        let mut answers = Answers::default();
+        answers.record(1, 1, "input", "1");
+        answers.record(1, 1, "input", "2");
+
+        assert_eq!(answers.answers.len(), 1);
+        assert_eq!(answers.get(1, 1, "input").unwrap().value, "2");
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers.record(10, 2, "noop\n", "#..\n.#.");
+        answers.record(1, 1, "1\n", "1");
+
+        let parsed: Answers = toml::from_str(&toml::to_string(&answers).unwrap()).unwrap();
+        assert_eq!(parsed.answers, answers.answers);
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::input_hash,
+    color::Colorize,
+    error::Error,
+    runner::{self, Puzzle},
+};
+
+/// Summary of a set of timings, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64,
+}
+
+impl Stats {
+    /// Summarizes the samples with nearest-rank percentiles.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let percentile = |p: f64| {
+            let rank = ((p * samples.len() as f64).ceil() as usize).max(1);
+            samples
+                .get(rank - 1)
+                .map(|sample| sample.as_secs_f64() * 1000.0)
+                .unwrap_or(0.0)
+        };
+
+        Stats {
+            min_ms: percentile(0.0),
+            median_ms: percentile(0.5),
+            p95_ms: percentile(0.95),
+        }
+    }
+}
+
+/// The timings of one part over several runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    /// Hash of the input, so baselines of different inputs aren't compared.
+    pub input: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Runs the puzzle `runs` times on the same input, failing on the first run
+/// that errors or panics.
+pub fn measure(puzzle: &Puzzle, input: &str, runs: usize) -> Result<Measurement, Error> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+
+    for _ in 0..runs.max(1) {
+        let report = runner::run_parallel(&[puzzle], 1, |_| Ok(input.to_string())).remove(0)?;
+        parse.push(report.parse);
+        solve.push(report.solve);
+    }
+
+    Ok(Measurement {
+        day: puzzle.day,
+        part: puzzle.part,
+        input: input_hash(input),
+        runs: parse.len(),
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+    })
+}
+
+/// Measurements saved by an earlier run, stored as a TOML file of
+/// `[[measurement]]` tables.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(rename = "measurement", default)]
+    measurements: Vec<Measurement>,
+}
+
+pub fn default_baseline_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("bench-baseline.toml")
+}
+
+impl Baseline {
+    /// Loads the baseline, `None` if none was saved yet.
+    pub fn load(path: &Path) -> Result<Option<Baseline>, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(Error::Io {
+                    path: Some(path.to_path_buf()),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&contents)
+            .map(Some)
+            .map_err(|e| Error::InvalidFile {
+                path: path.to_path_buf(),
+                message: e.message().to_string(),
+            })
+    }
+
+    /// Saves the measurements, keeping the ones of parts that weren't
+    /// measured this time.
+    pub fn save(&mut self, path: &Path, measurements: &[Measurement]) -> Result<(), Error> {
+        for measurement in measurements {
+            self.measurements.retain(|m| {
+                (m.day, m.part, &m.input) != (measurement.day, measurement.part, &measurement.input)
+            });
+            self.measurements.push(measurement.clone());
+        }
+        self.measurements
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+
+        let contents = toml::to_string(self).map_err(|e| Error::InvalidFile {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| Error::Io {
+                path: Some(dir.to_path_buf()),
+                source,
+            })?;
+        }
+
+        fs::write(path, contents).map_err(|source| Error::Io {
+            path: Some(path.to_path_buf()),
+            source,
+        })
+    }
+
+    pub fn get(&self, measurement: &Measurement) -> Option<&Measurement> {
+        self.measurements.iter().find(|m| {
+            (m.day, m.part, &m.input) == (measurement.day, measurement.part, &measurement.input)
+        })
+    }
+}
+
+/// Relative change of the median total time against the baseline, `0.1`
+/// meaning 10% slower.
+pub fn change(measurement: &Measurement, baseline: &Measurement) -> f64 {
+    let total = measurement.parse.median_ms + measurement.solve.median_ms;
+    let baseline_total = baseline.parse.median_ms + baseline.solve.median_ms;
+
+    if baseline_total == 0.0 {
+        0.0
+    } else {
+        total / baseline_total - 1.0
+    }
+}
+
+/// Prints the measurements as a table, comparing them against the baseline
+/// if there's one. Changes above `threshold` are highlighted.
+pub fn print_table(measurements: &[Measurement], baseline: Option<&Baseline>, threshold: f64) {
+    let stats_header = format!("{:>9} {:>9} {:>9}", "min", "median", "p95");
+    println!(
+        "{:>3}  {:>4}  {:<29}  {:<29}  vs baseline",
+        "Day", "Part", "Parse (ms)", "Solve (ms)"
+    );
+    println!("{:>3}  {:>4}  {}  {}", "", "", stats_header, stats_header);
+    println!("{}", "-".repeat(3 + 2 + 4 + 2 + 29 + 2 + 29 + 2 + 11));
+
+    for measurement in measurements {
+        let comparison = match baseline.and_then(|baseline| baseline.get(measurement)) {
+            Some(previous) => {
+                let change = change(measurement, previous);
+                let text = format!("{:+.1}%", change * 100.0);
+                if change > threshold {
+                    text.red().bold().to_string()
+                } else if change < -threshold {
+                    text.green().to_string()
+                } else {
+                    text
+                }
+            }
+            None => "-".to_string(),
+        };
+
+        println!(
+            "{:>3}  {:>4}  {}  {}  {}",
+            measurement.day,
+            measurement.part,
+            format_stats(&measurement.parse),
+            format_stats(&measurement.solve),
+            comparison
+        );
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{:>9.3} {:>9.3} {:>9.3}",
+        stats.min_ms, stats.median_ms, stats.p95_ms
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_nearest_rank_percentiles() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.median_ms, 10.0);
+        assert_eq!(stats.p95_ms, 19.0);
+    }
+
+    #[test]
+    fn single_sample_is_every_percentile() {
+        let stats = Stats::from_samples(&[Duration::from_millis(4)]);
+
+        assert_eq!(
+            (stats.min_ms, stats.median_ms, stats.p95_ms),
+            (4.0, 4.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn compares_median_totals() {
+        let stats = |median_ms| Stats {
+            min_ms: 0.0,
+            median_ms,
+            p95_ms: 0.0,
+        };
+        let measurement = |parse, solve| Measurement {
+            day: 1,
+            part: 1,
+            input: String::new(),
+            runs: 1,
+            parse: stats(parse),
+            solve: stats(solve),
+        };
+
+        let change = change(&measurement(1.0, 2.0), &measurement(1.0, 1.0));
+        assert!((change - 0.5).abs() < 1e-9);
+    }
+}
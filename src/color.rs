@@ -0,0 +1,37 @@
+//! Terminal colors, or plain text when built without the `color` feature.
+
+#[cfg(feature = "color")]
+pub use colored::Colorize;
+
+/// The few methods of `colored::Colorize` in use, leaving the text as it is.
+#[cfg(not(feature = "color"))]
+pub trait Colorize {
+    fn red(self) -> String;
+    fn green(self) -> String;
+    fn yellow(self) -> String;
+    fn bold(self) -> String;
+    fn dimmed(self) -> String;
+}
+
+#[cfg(not(feature = "color"))]
+impl<T: Into<String>> Colorize for T {
+    fn red(self) -> String {
+        self.into()
+    }
+
+    fn green(self) -> String {
+        self.into()
+    }
+
+    fn yellow(self) -> String {
+        self.into()
+    }
+
+    fn bold(self) -> String {
+        self.into()
+    }
+
+    fn dimmed(self) -> String {
+        self.into()
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    generate, reference,
+    runner::{self, Puzzle},
+};
+
+/// What the solution made of an input: its answer, or why it has none.
+type Outcome = Result<String, String>;
+
+/// A generated input on which a solution and its reference disagree.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    /// The seed and size the input was generated with.
+    pub seed: u64,
+    pub size: usize,
+    /// The input shrunk down to where removing anything more agrees.
+    pub input: String,
+    /// The answer of the reference.
+    pub expected: String,
+    pub actual: Outcome,
+}
+
+enum Check {
+    /// The puzzle has no answer for the input, the reference is undecided.
+    Skipped,
+    Agreed,
+    Disagreed {
+        expected: String,
+        actual: Outcome,
+    },
+}
+
+/// Runs the solution and its reference on inputs generated with the seeds
+/// `0..seeds`, their sizes cycling from 1 to `max_size`. Returns how many
+/// inputs both answered the same, or the first one they disagree on shrunk
+/// to a minimal reproduction.
+///
+/// Panics are caught but still reach the panic hook, callers wanting a quiet
+/// run have to replace it.
+pub fn crosscheck(puzzle: &Puzzle, seeds: u64, max_size: usize) -> Result<usize, Mismatch> {
+    let mut agreed = 0;
+    for seed in 0..seeds {
+        let size = 1 + (seed as usize) % max_size.max(1);
+        let Some(input) = generate::generate(puzzle.day, size, seed) else {
+            continue;
+        };
+        let failed = match check(puzzle, &input) {
+            Check::Skipped => continue,
+            Check::Agreed => {
+                agreed += 1;
+                continue;
+            }
+            Check::Disagreed { actual, .. } => actual.is_err(),
+        };
+
+        // A wrong answer shrinks to a wrong answer and a failure to a
+        // failure, not to an input one of the parsers rejects
+        let input = shrink(&input, |candidate| match check(puzzle, candidate) {
+            Check::Disagreed { actual, .. } => actual.is_err() == failed,
+            _ => false,
+        });
+        let (expected, actual) = match check(puzzle, &input) {
+            Check::Disagreed { expected, actual } => (expected, actual),
+            _ => unreachable!("the input was shrunk to one that disagrees"),
+        };
+
+        return Err(Mismatch {
+            seed,
+            size,
+            input,
+            expected,
+            actual,
+        });
+    }
+
+    Ok(agreed)
+}
+
+fn check(puzzle: &Puzzle, input: &str) -> Check {
+    let Some(expected) = reference::solve(puzzle.day, puzzle.part, input) else {
+        return Check::Skipped;
+    };
+    let actual = runner::run_parallel(&[puzzle], 1, |_| Ok(input.to_string()))
+        .remove(0)
+        .map(|report| report.answer)
+        .map_err(|e| e.to_string());
+
+    if actual.as_ref() == Ok(&expected) {
+        Check::Agreed
+    } else {
+        Check::Disagreed { expected, actual }
+    }
+}
+
+/// Removes lines of the input, then characters of the lines left, as long as
+/// `fails` keeps failing.
+fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join =
+        |lines: &[String]| -> String { lines.iter().map(|line| format!("{}\n", line)).collect() };
+
+    let lines: Vec<String> = input.lines().map(str::to_string).collect();
+    let mut lines = remove_chunks(lines, |lines| fails(&join(lines)));
+
+    for i in 0..lines.len() {
+        let chars: Vec<char> = lines[i].chars().collect();
+        let chars = remove_chunks(chars, |chars| {
+            let mut candidate = lines.clone();
+            candidate[i] = chars.iter().collect();
+            fails(&join(&candidate))
+        });
+        lines[i] = chars.into_iter().collect();
+    }
+
+    join(&lines)
+}
+
+/// Removes chunks of items while `fails` keeps failing, halving the chunks
+/// until single items can't be removed either.
+fn remove_chunks<T: Clone>(mut items: Vec<T>, fails: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunk = items.len().div_ceil(2);
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + chunk).min(items.len());
+            let candidate: Vec<T> = items[..start]
+                .iter()
+                .chain(&items[end..])
+                .cloned()
+                .collect();
+            if fails(&candidate) {
+                items = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_the_failing_lines() {
+        let input = "1\n2\n3\n42\n5\n6\n7\n";
+        let shrunk = shrink(input, |candidate| candidate.contains('4'));
+
+        assert_eq!(shrunk, "4\n");
+    }
+
+    #[test]
+    fn solutions_agree_with_their_references() {
+        for puzzle in runner::PUZZLES {
+            if let Err(mismatch) = crosscheck(puzzle, 20, 10) {
+                panic!("day {} part {}: {:?}", puzzle.day, puzzle.part, mismatch);
+            }
+        }
+    }
+}
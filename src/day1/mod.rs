@@ -0,0 +1,395 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::BufRead,
+};
+
+use serde::Serialize;
+
+use crate::{
+    error::{parse_number, Error, ParseError},
+    generate::Rng,
+    solution::Solution,
+};
+
+pub struct Day1;
+
+/// The calories an elf carries, summed over its items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub calories: usize,
+    pub items: usize,
+}
+
+/// The elves of the input, read a line at a time so that only the elf being
+/// summed is in memory. Lines may end with CRLF, and any number of blank
+/// lines separate the elves.
+pub struct Elves<R> {
+    reader: R,
+    line: String,
+    /// Lines read so far, to locate errors.
+    lines: usize,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Elves<R> {
+        Elves {
+            reader,
+            line: String::new(),
+            lines: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut elf: Option<Elf> = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return elf.map(Ok),
+                Ok(_) => self.lines += 1,
+                Err(source) => return Some(Err(Error::Io { path: None, source })),
+            }
+
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                match elf {
+                    Some(elf) => return Some(Ok(elf)),
+                    None => continue,
+                }
+            }
+
+            match parse_number::<usize>(line, line) {
+                Ok(calories) => {
+                    let elf = elf.get_or_insert(Elf {
+                        calories: 0,
+                        items: 0,
+                    });
+                    elf.calories += calories;
+                    elf.items += 1;
+                }
+                Err(e) => {
+                    let source = e.offset(self.lines - 1);
+                    return Some(Err(Error::Parse { day: 1, source }));
+                }
+            }
+        }
+    }
+}
+
+/// An elf among the ones carrying the most calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked {
+    /// Where the elf is in the input, the first one being 0.
+    pub position: usize,
+    pub calories: usize,
+}
+
+impl Ord for Ranked {
+    /// More calories rank higher, and on a tie the elf coming first.
+    fn cmp(&self, other: &Ranked) -> Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then(other.position.cmp(&self.position))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Ranked) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `n` elves carrying the most calories, best first, out of the total
+/// calories of each elf in input order. Only `n` elves are kept at a time,
+/// and fewer are returned when there aren't `n`.
+pub fn top_n(totals: impl IntoIterator<Item = usize>, n: usize) -> Vec<Ranked> {
+    // The lowest ranked elf kept is on top, to be replaced by a better one
+    let mut kept = BinaryHeap::new();
+    for (position, calories) in totals.into_iter().enumerate() {
+        kept.push(Reverse(Ranked { position, calories }));
+        if kept.len() > n {
+            kept.pop();
+        }
+    }
+
+    kept.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(ranked)| ranked)
+        .collect()
+}
+
+/// [`top_n`] of the elves read from `reader`, stopping at the first error.
+pub fn read_top_n(reader: impl BufRead, n: usize) -> Result<Vec<Ranked>, Error> {
+    let mut error = None;
+    let totals = Elves::new(reader)
+        .map_while(|elf| elf.map_err(|e| error = Some(e)).ok())
+        .map(|elf| elf.calories);
+    let ranked = top_n(totals, n);
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(ranked),
+    }
+}
+
+/// The calories of the `n` elves carrying the most.
+fn top_calories(elfs: &[Elf], n: usize) -> usize {
+    top_n(elfs.iter().map(|elf| elf.calories), n)
+        .iter()
+        .map(|ranked| ranked.calories)
+        .sum()
+}
+
+/// The percentiles reported by [`Stats`], the median aside.
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Most bins in the histogram of [`Stats`].
+const BINS: usize = 10;
+
+/// Descriptive statistics of the calories the elves carry.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub count: usize,
+    pub total: usize,
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation.
+    pub std_dev: f64,
+    pub percentiles: Vec<Percentile>,
+    /// The elf carrying the most items, the first of them on a tie.
+    pub most_items: MostItems,
+    pub histogram: Vec<Bin>,
+}
+
+/// The calories that `percent` of the elves carry at most, by the nearest
+/// rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Percentile {
+    pub percent: u8,
+    pub calories: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MostItems {
+    /// Where the elf is in the input, the first one being 0.
+    pub position: usize,
+    pub items: usize,
+    pub calories: usize,
+}
+
+/// The number of elves carrying from `from` to `to` calories, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bin {
+    pub from: usize,
+    pub to: usize,
+    pub count: usize,
+}
+
+impl Stats {
+    /// The statistics of the elves, `None` when there are none.
+    pub fn new(elfs: &[Elf]) -> Option<Stats> {
+        let mut totals: Vec<usize> = elfs.iter().map(|elf| elf.calories).collect();
+        totals.sort_unstable();
+        let (&min, &max) = (totals.first()?, totals.last()?);
+
+        let count = totals.len();
+        let total: usize = totals.iter().sum();
+        let mean = total as f64 / count as f64;
+        let median = if count % 2 == 1 {
+            totals[count / 2] as f64
+        } else {
+            (totals[count / 2 - 1] + totals[count / 2]) as f64 / 2.0
+        };
+        let variance = totals
+            .iter()
+            .map(|&calories| (calories as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percent| {
+                let rank = (usize::from(percent) * count).div_ceil(100).max(1);
+                Percentile {
+                    percent,
+                    calories: totals[rank - 1],
+                }
+            })
+            .collect();
+
+        // max_by_key keeps the last of the maximums, reversing keeps the first
+        let (position, elf) = elfs
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, elf)| elf.items)?;
+        let most_items = MostItems {
+            position,
+            items: elf.items,
+            calories: elf.calories,
+        };
+
+        Some(Stats {
+            count,
+            total,
+            min,
+            max,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            percentiles,
+            most_items,
+            histogram: histogram(&totals),
+        })
+    }
+}
+
+/// Splits the range of the sorted totals into at most [`BINS`] bins of the
+/// same width.
+fn histogram(totals: &[usize]) -> Vec<Bin> {
+    let (min, max) = (totals[0], totals[totals.len() - 1]);
+    let width = (max - min + 1).div_ceil(BINS);
+
+    let mut bins: Vec<Bin> = (0..(max - min) / width + 1)
+        .map(|i| Bin {
+            from: min + i * width,
+            to: min + (i + 1) * width - 1,
+            count: 0,
+        })
+        .collect();
+    for &calories in totals {
+        bins[(calories - min) / width].count += 1;
+    }
+    bins
+}
+
+impl Solution for Day1 {
+    type Parsed = Vec<Elf>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Elves::new(input.as_bytes())
+            .collect::<Result<_, _>>()
+            .map_err(|e| match e {
+                Error::Parse { source, .. } => source,
+                e => unreachable!("reading from memory failed: {}", e),
+            })
+    }
+
+    fn part1(elfs: &Self::Parsed) -> Self::Part1 {
+        top_calories(elfs, 1)
+    }
+
+    fn part2(elfs: &Self::Parsed) -> Self::Part2 {
+        top_calories(elfs, 3)
+    }
+}
+
+/// Generates `size` elves, at least the three part 2 needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elfs: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            let n_items = rng.range(1..=15);
+            (0..n_items)
+                .map(|_| rng.range(1000..=60000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect();
+
+    elfs.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&parsed), 24000);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&parsed), 45000);
+    }
+
+    #[test]
+    fn ranks_ties_by_position() {
+        let ranked = top_n([3000, 5000, 3000, 1000, 5000], 3);
+        let positions: Vec<usize> = ranked.iter().map(|elf| elf.position).collect();
+        assert_eq!(positions, [1, 4, 0]);
+
+        assert_eq!(top_n([3000, 5000], 3).len(), 2);
+        assert!(top_n([3000], 0).is_empty());
+        assert_eq!(top_n([3000, 5000], usize::MAX).len(), 2);
+        assert_eq!(Day1::part2(&Day1::parse("1000\n\n2000").unwrap()), 3000);
+    }
+
+    #[test]
+    fn describes_the_example() {
+        let parsed = Day1::parse(EXAMPLE).unwrap();
+        let stats = Stats::new(&parsed).unwrap();
+
+        assert_eq!((stats.count, stats.total), (5, 55000));
+        assert_eq!((stats.min, stats.max), (4000, 24000));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert!((stats.std_dev - 6985.7).abs() < 0.1, "{}", stats.std_dev);
+        let percentiles: Vec<usize> = stats.percentiles.iter().map(|p| p.calories).collect();
+        assert_eq!(percentiles, [4000, 6000, 11000, 24000, 24000]);
+        assert_eq!(
+            stats.most_items,
+            MostItems {
+                position: 0,
+                items: 3,
+                calories: 6000
+            }
+        );
+
+        let counts: Vec<usize> = stats.histogram.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, [2, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(stats.histogram.last().unwrap().to, 24009);
+
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn streams_crlf_and_blank_runs() {
+        let input = "\r\n1000\r\n2000\r\n\r\n\r\n\r\n4000\n\n\n5000\n6000";
+        let elves: Vec<Elf> = Elves::new(input.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let totals: Vec<usize> = elves.iter().map(|elf| elf.calories).collect();
+        assert_eq!(totals, [3000, 4000, 11000]);
+        assert_eq!(elves[2].items, 2);
+
+        let ranked = read_top_n(input.as_bytes(), 1).unwrap();
+        assert_eq!(
+            ranked,
+            [Ranked {
+                position: 2,
+                calories: 11000
+            }]
+        );
+    }
+
+    #[test]
+    fn locates_bad_calories() {
+        let error = Day1::parse("1000\r\n\r\n\r\n20x0\r\n").unwrap_err();
+        assert_eq!(error.location.unwrap().line, 4);
+
+        let error = read_top_n("1000\n\n-5\n".as_bytes(), 3).unwrap_err();
+        assert!(error.to_string().contains("line 3"), "{}", error);
+    }
+}
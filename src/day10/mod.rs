@@ -0,0 +1,342 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    animate::Simulation,
+    error::{parse_lines, parse_number, ParseError},
+    generate::Rng,
+    repl::{self, Session},
+    solution::Solution,
+};
+
+pub struct Day10;
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+impl Operation {
+    fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Cpu {
+    register_x: i32,
+    cycle: usize,
+    operation_cycle: usize,
+    operation: usize,
+    operations: Vec<Operation>,
+}
+
+pub struct Crt {
+    pixels: Vec<bool>,
+}
+
+impl Cpu {
+    fn start_cycle(&mut self) {
+        self.cycle += 1;
+        self.operation_cycle += 1;
+    }
+
+    fn end_cycle(&mut self) {
+        let op = self.operations.get(self.operation).unwrap();
+
+        if self.operation_cycle == op.cycles() {
+            if let Operation::Addx(arg) = op {
+                self.register_x += arg;
+            }
+
+            self.operation += 1;
+            self.operation_cycle = 0;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.operation >= self.operations.len()
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            None if s == "noop" => Ok(Operation::Noop),
+            Some(("addx", value_str)) => Ok(Operation::Addx(parse_number(s, value_str)?)),
+            _ => Err(ParseError::at(s, 0, "expected 'noop' or 'addx'")),
+        }
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in 0..(40 * 6) {
+            let pixel = self.pixels.get(i).unwrap();
+
+            if i != 0 && i % 40 == 0 {
+                writeln!(f)?;
+            }
+
+            if *pixel {
+                write!(f, "#")?;
+            } else {
+                write!(f, ".")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The CRT drawing its pixels one cycle at a time.
+struct Drawing {
+    cpu: Cpu,
+    display: Crt,
+}
+
+impl Drawing {
+    fn new(operations: &[Operation]) -> Drawing {
+        Drawing {
+            cpu: Cpu {
+                register_x: 1,
+                operations: operations.to_vec(),
+                ..Default::default()
+            },
+            display: Crt {
+                pixels: vec![false; 40 * 6],
+            },
+        }
+    }
+}
+
+impl Simulation for Drawing {
+    fn step(&mut self) -> bool {
+        if self.cpu.is_done() {
+            return false;
+        }
+
+        // Programs may run past the last pixel, the CRT just stops drawing
+        let x = self.cpu.cycle % 40;
+        if let Some(pixel) = self.display.pixels.get_mut(self.cpu.cycle) {
+            *pixel = (x as i32).abs_diff(self.cpu.register_x) <= 1;
+        }
+
+        self.cpu.start_cycle();
+        self.cpu.end_cycle();
+        true
+    }
+
+    fn frame(&self) -> String {
+        let sprite: String = (0..40i32)
+            .map(|x| {
+                if x.abs_diff(self.cpu.register_x) <= 1 {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", self.display, sprite)
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "cycle {}, X = {}, the last row is the sprite",
+            self.cpu.cycle, self.cpu.register_x
+        )
+    }
+}
+
+/// Runs the program a few cycles at a time.
+struct Debugger {
+    operations: Vec<Operation>,
+    drawing: Drawing,
+}
+
+impl Debugger {
+    fn state(&self) -> String {
+        let cpu = &self.drawing.cpu;
+        let next = match cpu.operations.get(cpu.operation) {
+            None => "the program ended".to_string(),
+            Some(Operation::Noop) => "running noop".to_string(),
+            Some(Operation::Addx(arg)) => {
+                format!(
+                    "running addx {}, cycle {} of 2",
+                    arg,
+                    cpu.operation_cycle + 1
+                )
+            }
+        };
+        format!("cycle {}, X = {}, {}", cpu.cycle, cpu.register_x, next)
+    }
+}
+
+const COMMANDS: &[repl::Command] = &[
+    repl::Command {
+        name: "step",
+        args: "[cycles]",
+        help: "run the program for some cycles, one by default",
+    },
+    repl::Command {
+        name: "screen",
+        args: "",
+        help: "what the CRT drew so far and the sprite",
+    },
+    repl::Command {
+        name: "reset",
+        args: "",
+        help: "start the program over",
+    },
+];
+
+impl Session for Debugger {
+    fn commands(&self) -> &'static [repl::Command] {
+        COMMANDS
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                let cycles: usize = repl::arg_or(args, 0, "number of cycles", 1)?;
+                for _ in 0..cycles {
+                    if !self.drawing.step() {
+                        break;
+                    }
+                }
+                Ok(self.state())
+            }
+            "screen" => Ok(self.drawing.frame().trim_end().to_string()),
+            "reset" => {
+                self.drawing = Drawing::new(&self.operations);
+                Ok(self.state())
+            }
+            _ => Err(format!("unknown command '{}'", command)),
+        }
+    }
+}
+
+pub fn explore(input: &str, _part: u8) -> Result<Box<dyn Session>, ParseError> {
+    let operations = Day10::parse(input)?;
+    Ok(Box::new(Debugger {
+        drawing: Drawing::new(&operations),
+        operations,
+    }))
+}
+
+impl Solution for Day10 {
+    type Parsed = Vec<Operation>;
+    type Part1 = i32;
+    type Part2 = Crt;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(operations: &Self::Parsed) -> Self::Part1 {
+        let mut cpu = Cpu {
+            register_x: 1,
+            operations: operations.clone(),
+            ..Default::default()
+        };
+
+        let mut signal_strength_sum: i32 = 0;
+
+        while !cpu.is_done() {
+            cpu.start_cycle();
+
+            if cpu.cycle == 20
+                || cpu.cycle == 60
+                || cpu.cycle == 100
+                || cpu.cycle == 140
+                || cpu.cycle == 180
+                || cpu.cycle == 220
+            {
+                signal_strength_sum += cpu.cycle as i32 * cpu.register_x;
+            }
+            cpu.end_cycle();
+        }
+
+        signal_strength_sum
+    }
+
+    fn part2(operations: &Self::Parsed) -> Self::Part2 {
+        let mut drawing = Drawing::new(operations);
+        while drawing.step() {}
+
+        drawing.display
+    }
+}
+
+/// Draws the CRT one cycle at a time, there's only the drawing of part 2 to
+/// animate.
+pub fn simulate(input: &str, _part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Drawing::new(&Day10::parse(input)?)))
+}
+
+/// Generates a program running for `size` cycles, which may be more than the
+/// 240 the CRT has pixels for.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cycles = size;
+    let mut used = 0;
+    let mut input = String::new();
+
+    while used < cycles {
+        if cycles - used >= 2 && rng.chance(0.6) {
+            let value = rng.range(0..=30) as i32 - 15;
+            input.push_str(&format!("addx {}\n", value));
+            used += 2;
+        } else {
+            input.push_str("noop\n");
+            used += 1;
+        }
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&parsed), 13140);
+    }
+
+    #[test]
+    fn stops_drawing_past_the_last_pixel() {
+        let parsed = Day10::parse(&"noop\n".repeat(300)).unwrap();
+        let screen = Day10::part2(&parsed).to_string();
+
+        assert_eq!(screen.lines().count(), 6);
+        assert_eq!(
+            screen.lines().next().unwrap(),
+            format!("###{}", ".".repeat(37))
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day10::part2(&parsed).to_string(),
+            "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+}
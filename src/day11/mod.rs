@@ -0,0 +1,591 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{fmt, str::FromStr};
+
+use crate::{
+    error::{parse_number, ParseError},
+    generate::Rng,
+    repl::{self, Session},
+    solution::Solution,
+};
+
+pub struct Day11;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Divide(u64),
+    Modulo(u64),
+    Add(u64),
+    Multiply(u64),
+    AddSelf,
+    MultiplySelf,
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    worry_level: u64,
+}
+
+impl Item {
+    fn apply_op(&mut self, operation: &Operation) {
+        self.worry_level = match operation {
+            Operation::Divide(arg) => self.worry_level / arg,
+            Operation::Modulo(arg) => self.worry_level % arg,
+            Operation::Add(arg) => self.worry_level + arg,
+            Operation::Multiply(arg) => self.worry_level * arg,
+            Operation::AddSelf => self.worry_level + self.worry_level,
+            Operation::MultiplySelf => self.worry_level * self.worry_level,
+        };
+    }
+
+    fn test_op(&self, operation: &Operation) -> bool {
+        match operation {
+            Operation::Divide(arg) => self.worry_level.is_multiple_of(*arg),
+            _ => panic!("Can't test other than divisible op"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    name: usize,
+    items: Vec<Item>,
+    operation: Operation,
+    test: Operation,
+    truthy_target: usize,
+    falsey_target: usize,
+    n_inspections: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    /// Parses the six lines describing a monkey, errors are located relative
+    /// to its first line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref MonkeyRE: Regex = Regex::new(r"^Monkey (\d+):$").unwrap();
+            static ref ItemsRE: Regex = Regex::new(r"^\s*Starting items:\s*(.*)$").unwrap();
+            static ref OperationRE: Regex =
+                Regex::new(r"^\s*Operation: new = old ([+*]) (\d+|old)$").unwrap();
+            static ref TestRE: Regex = Regex::new(r"^\s*Test: divisible by (\d+)$").unwrap();
+            static ref TruthyRE: Regex =
+                Regex::new(r"^\s*If true: throw to monkey (\d+)$").unwrap();
+            static ref FalseyRE: Regex =
+                Regex::new(r"^\s*If false: throw to monkey (\d+)$").unwrap();
+        }
+
+        let lines = s.lines().collect::<Vec<&str>>();
+        let line = |i: usize, re: &Regex, expected: &str| {
+            let line = lines.get(i).copied().unwrap_or("");
+            re.captures(line)
+                .map(|captures| (line, captures))
+                .ok_or_else(|| ParseError::at(line, 0, format!("expected {}", expected)).offset(i))
+        };
+
+        let (monkey_s, captures) = line(0, &MonkeyRE, "'Monkey N:'")?;
+        let name = parse_number(monkey_s, &captures[1])?;
+
+        let (items_s, captures) = line(1, &ItemsRE, "the starting items")?;
+        let items = captures[1]
+            .split(", ")
+            .filter(|item| !item.is_empty())
+            .map(|item| parse_number(items_s, item).map(|worry_level| Item { worry_level }))
+            .collect::<Result<Vec<Item>, ParseError>>()
+            .map_err(|e| e.offset(1))?;
+
+        let (operation_s, captures) = line(2, &OperationRE, "the operation")?;
+        let operation = match (&captures[1], &captures[2]) {
+            ("*", "old") => Operation::MultiplySelf,
+            ("+", "old") => Operation::AddSelf,
+            ("*", arg) => {
+                Operation::Multiply(parse_number(operation_s, arg).map_err(|e| e.offset(2))?)
+            }
+            (_, arg) => Operation::Add(parse_number(operation_s, arg).map_err(|e| e.offset(2))?),
+        };
+
+        let (test_s, captures) = line(3, &TestRE, "the divisibility test")?;
+        let divisor = parse_number(test_s, &captures[1]).map_err(|e| e.offset(3))?;
+        if divisor == 0 {
+            let message = "can't test for divisibility by 0";
+            return Err(ParseError::span(test_s, &captures[1], message).offset(3));
+        }
+        let test = Operation::Divide(divisor);
+
+        let (truthy_target_s, captures) = line(4, &TruthyRE, "the target if true")?;
+        let truthy_target = parse_number(truthy_target_s, &captures[1]).map_err(|e| e.offset(4))?;
+
+        let (falsey_target_s, captures) = line(5, &FalseyRE, "the target if false")?;
+        let falsey_target = parse_number(falsey_target_s, &captures[1]).map_err(|e| e.offset(5))?;
+
+        let monkey = Monkey {
+            name,
+            items,
+            operation,
+            test,
+            truthy_target,
+            falsey_target,
+            n_inspections: 0,
+        };
+
+        Ok(monkey)
+    }
+}
+
+impl fmt::Display for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items: Vec<String> = self
+            .items
+            .iter()
+            .map(|item| item.worry_level.to_string())
+            .collect();
+        let operation = match &self.operation {
+            Operation::Add(arg) => format!("old + {}", arg),
+            Operation::Multiply(arg) => format!("old * {}", arg),
+            Operation::AddSelf => "old + old".to_string(),
+            Operation::MultiplySelf => "old * old".to_string(),
+            _ => return Err(fmt::Error),
+        };
+        let Operation::Divide(divisor) = self.test else {
+            return Err(fmt::Error);
+        };
+
+        writeln!(f, "Monkey {}:", self.name)?;
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: new = {}", operation)?;
+        writeln!(f, "  Test: divisible by {}", divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.truthy_target)?;
+        writeln!(f, "    If false: throw to monkey {}", self.falsey_target)
+    }
+}
+
+impl Monkey {
+    fn do_turn(&mut self, relief: &Operation) -> Option<(usize, Item)> {
+        if self.items.is_empty() {
+            return None;
+        }
+
+        let mut item = self.items.remove(0);
+        self.n_inspections += 1;
+        item.apply_op(&self.operation);
+        item.apply_op(relief);
+
+        if item.test_op(&self.test) {
+            Some((self.truthy_target, item))
+        } else {
+            Some((self.falsey_target, item))
+        }
+    }
+
+    fn throw(&mut self, item: Item) {
+        self.items.push(item);
+    }
+}
+
+#[derive(Debug)]
+struct Simulation {
+    round: i32,
+    monkeys: Vec<Monkey>,
+    relief: Operation,
+}
+
+impl Simulation {
+    fn new(monkeys: Vec<Monkey>, relief: Operation) -> Simulation {
+        Simulation {
+            round: 0,
+            monkeys,
+            relief,
+        }
+    }
+
+    fn common_denominator(monkeys: &[Monkey]) -> u64 {
+        monkeys
+            .iter()
+            .map(|m| match m.test {
+                Operation::Divide(arg) => arg,
+                _ => panic!("Test isn't division"),
+            })
+            .product()
+    }
+
+    fn do_round(&mut self) {
+        for i in 0..self.monkeys.len() {
+            while let Some((target, item)) = self.do_turn_on(i) {
+                self.monkeys.get_mut(target).unwrap().throw(item);
+            }
+        }
+
+        self.round += 1;
+    }
+
+    fn do_turn_on(&mut self, monkey: usize) -> Option<(usize, Item)> {
+        self.monkeys.get_mut(monkey).unwrap().do_turn(&self.relief)
+    }
+
+    fn monkey_business(&self) -> usize {
+        let mut n_inspections: Vec<usize> = self
+            .monkeys
+            .iter()
+            .map(|monkey| monkey.n_inspections)
+            .collect();
+
+        n_inspections.sort_by(|a, b| b.cmp(a));
+
+        n_inspections.first().unwrap() * n_inspections.get(1).unwrap()
+    }
+}
+
+/// Plays the rounds of a part a few at a time.
+struct Monkeys {
+    monkeys: Vec<Monkey>,
+    relief: Operation,
+    simulation: Simulation,
+}
+
+impl Monkeys {
+    fn inspections(&self) -> String {
+        let lines: Vec<String> = self
+            .simulation
+            .monkeys
+            .iter()
+            .map(|monkey| {
+                format!(
+                    "monkey {} inspected {} items",
+                    monkey.name, monkey.n_inspections
+                )
+            })
+            .collect();
+        format!("round {}\n{}", self.simulation.round, lines.join("\n"))
+    }
+}
+
+const COMMANDS: &[repl::Command] = &[
+    repl::Command {
+        name: "monkey",
+        args: "<n>",
+        help: "the items of the monkey and how often it inspected them",
+    },
+    repl::Command {
+        name: "round",
+        args: "[rounds]",
+        help: "play some rounds, one by default",
+    },
+    repl::Command {
+        name: "business",
+        args: "",
+        help: "the monkey business so far",
+    },
+    repl::Command {
+        name: "reset",
+        args: "",
+        help: "start over from the first round",
+    },
+];
+
+impl Session for Monkeys {
+    fn commands(&self) -> &'static [repl::Command] {
+        COMMANDS
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "monkey" => {
+                let n: usize = repl::arg(args, 0, "monkey")?;
+                let monkey = self
+                    .simulation
+                    .monkeys
+                    .get(n)
+                    .ok_or_else(|| format!("there are only {} monkeys", self.monkeys.len()))?;
+                Ok(format!(
+                    "{}  Inspected {} items",
+                    monkey, monkey.n_inspections
+                ))
+            }
+            "round" => {
+                let rounds: usize = repl::arg_or(args, 0, "number of rounds", 1)?;
+                for _ in 0..rounds {
+                    self.simulation.do_round();
+                }
+                Ok(self.inspections())
+            }
+            "business" => Ok(self.simulation.monkey_business().to_string()),
+            "reset" => {
+                self.simulation = Simulation::new(self.monkeys.clone(), self.relief.clone());
+                Ok(self.inspections())
+            }
+            _ => Err(format!("unknown command '{}'", command)),
+        }
+    }
+}
+
+/// Plays the rounds with the relief of part 1, or keeping the worry levels
+/// manageable like part 2.
+pub fn explore(input: &str, part: u8) -> Result<Box<dyn Session>, ParseError> {
+    let monkeys = Day11::parse(input)?;
+    let relief = match part {
+        1 => Operation::Divide(3),
+        _ => Operation::Modulo(Simulation::common_denominator(&monkeys)),
+    };
+
+    Ok(Box::new(Monkeys {
+        simulation: Simulation::new(monkeys.clone(), relief.clone()),
+        monkeys,
+        relief,
+    }))
+}
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = input.replace('\r', "");
+
+        let mut monkeys = vec![];
+        let mut offsets = vec![];
+        let mut offset = 0;
+        for block in input.split("\n\n") {
+            monkeys.push(block.parse::<Monkey>().map_err(|e| e.offset(offset))?);
+            offsets.push((offset, block));
+            offset += block.lines().count() + 1;
+        }
+
+        if monkeys.len() < 2 {
+            return Err(ParseError::new("expected at least two monkeys"));
+        }
+
+        // The targets are on the last two lines of each monkey
+        for (monkey, (offset, block)) in monkeys.iter().zip(offsets) {
+            for (i, target) in [(4, monkey.truthy_target), (5, monkey.falsey_target)] {
+                if target >= monkeys.len() {
+                    let line = block.lines().nth(i).unwrap_or("");
+                    let number = line.rsplit(' ').next().unwrap_or("");
+                    let message =
+                        format!("there's no monkey {}, there are {}", target, monkeys.len());
+                    return Err(ParseError::span(line, number, message).offset(offset + i));
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed) -> Self::Part1 {
+        let mut simulation = Simulation::new(monkeys.clone(), Operation::Divide(3));
+
+        for _ in 0..20 {
+            simulation.do_round();
+        }
+
+        simulation.monkey_business()
+    }
+
+    fn part2(monkeys: &Self::Parsed) -> Self::Part2 {
+        let common_denominator = Simulation::common_denominator(monkeys);
+        let mut simulation =
+            Simulation::new(monkeys.clone(), Operation::Modulo(common_denominator));
+
+        for _ in 0..10000 {
+            simulation.do_round();
+        }
+
+        simulation.monkey_business()
+    }
+}
+
+/// Generates up to 8 monkeys holding `size` items between them. Specs whose
+/// worry levels would overflow in part 1 are thrown away and generated again,
+/// with fewer multiplications every time. Only adding always fits, as the
+/// relief then shrinks the worry levels.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n_monkeys = (size / 4).clamp(2, 8);
+    // The product of the divisors stays small enough to square in part 2
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    for attempt in 0.. {
+        rng.shuffle(&mut divisors);
+        let squaring = if attempt < 10 {
+            Some(rng.below(n_monkeys))
+        } else {
+            None
+        };
+        let multiplying = if attempt < 20 { 0.5 } else { 0.0 };
+
+        let mut monkeys: Vec<Monkey> = (0..n_monkeys)
+            .map(|i| {
+                let operation = if Some(i) == squaring {
+                    Operation::MultiplySelf
+                } else if rng.chance(1.0 - multiplying) {
+                    Operation::Add(rng.range(1..=8) as u64)
+                } else {
+                    Operation::Multiply(rng.range(2..=19) as u64)
+                };
+                let mut target = || (i + rng.range(1..=n_monkeys - 1)) % n_monkeys;
+
+                Monkey {
+                    name: i,
+                    items: vec![],
+                    operation,
+                    test: Operation::Divide(divisors[i]),
+                    truthy_target: target(),
+                    falsey_target: target(),
+                    n_inspections: 0,
+                }
+            })
+            .collect();
+
+        for _ in 0..size {
+            let worry_level = rng.range(50..=99) as u64;
+            monkeys[rng.below(n_monkeys)]
+                .items
+                .push(Item { worry_level });
+        }
+
+        if fits_in_u64(&monkeys) {
+            return monkeys
+                .iter()
+                .map(|monkey| monkey.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+        }
+    }
+
+    unreachable!("monkeys only adding always fit")
+}
+
+/// Whether part 1 can be simulated without the worry levels overflowing.
+fn fits_in_u64(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().map(|item| item.worry_level).collect())
+        .collect();
+
+    for _ in 0..20 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for worry_level in std::mem::take(&mut items[i]) {
+                let worry_level = match monkey.operation {
+                    Operation::Add(arg) => worry_level.checked_add(arg),
+                    Operation::Multiply(arg) => worry_level.checked_mul(arg),
+                    Operation::AddSelf => worry_level.checked_add(worry_level),
+                    Operation::MultiplySelf => worry_level.checked_mul(worry_level),
+                    _ => Some(worry_level),
+                };
+                let Some(worry_level) = worry_level.map(|worry_level| worry_level / 3) else {
+                    return false;
+                };
+
+                let item = Item { worry_level };
+                let target = if item.test_op(&monkey.test) {
+                    monkey.truthy_target
+                } else {
+                    monkey.falsey_target
+                };
+                items[target].push(item.worry_level);
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&parsed), 10605);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&parsed), 2713310158);
+    }
+
+    #[test]
+    fn parses_monkey() {
+        let monkey = "Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1"
+            .parse::<Monkey>()
+            .unwrap();
+
+        assert_eq!(monkey.name, 3);
+        assert_eq!(monkey.items.len(), 1);
+        assert_eq!(monkey.items[0].worry_level, 74);
+        assert!(matches!(monkey.operation, Operation::Add(3)));
+        assert!(matches!(monkey.test, Operation::Divide(17)));
+        assert_eq!((monkey.truthy_target, monkey.falsey_target), (0, 1));
+    }
+
+    #[test]
+    fn parses_operations_on_old() {
+        let parse = |operation: &str| {
+            format!(
+                "Monkey 0:
+  Starting items: 79, 60, 97
+  Operation: new = {}
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3",
+                operation
+            )
+            .parse::<Monkey>()
+            .unwrap()
+        };
+
+        assert!(matches!(
+            parse("old * old").operation,
+            Operation::MultiplySelf
+        ));
+        assert!(matches!(parse("old + old").operation, Operation::AddSelf));
+        assert!(matches!(
+            parse("old * 19").operation,
+            Operation::Multiply(19)
+        ));
+        assert_eq!(parse("old * old").items.len(), 3);
+    }
+
+    #[test]
+    fn rejects_truncated_monkey() {
+        assert!("Monkey 0:\n  Starting items: 79".parse::<Monkey>().is_err());
+    }
+
+    #[test]
+    fn locates_errors_in_later_monkeys() {
+        let input = EXAMPLE.replace("new = old * 19", "new = old - 19");
+        let error = Day11::parse(&input).unwrap_err();
+
+        let location = error.location.unwrap();
+        assert_eq!(location.line, 3);
+        assert_eq!(location.text, "  Operation: new = old - 19");
+
+        let input = EXAMPLE.replace("divisible by 13", "divisible by 0");
+        let error = Day11::parse(&input).unwrap_err();
+
+        assert_eq!(error.location.unwrap().line, 18);
+    }
+
+    #[test]
+    fn rejects_missing_monkeys() {
+        let first = EXAMPLE.split("\n\n").next().unwrap();
+        let error = Day11::parse(first).unwrap_err();
+        assert_eq!(error.message, "expected at least two monkeys");
+
+        let input = EXAMPLE.replacen("throw to monkey 3", "throw to monkey 4", 1);
+        let error = Day11::parse(&input).unwrap_err();
+        assert_eq!(error.message, "there's no monkey 4, there are 4");
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (6, 31));
+        assert_eq!(location.text, "    If false: throw to monkey 4");
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt::Display;
+
+use crate::{
+    animate::Simulation,
+    error::ParseError,
+    generate::Rng,
+    geometry::{Grid, Point},
+    solution::Solution,
+};
+
+pub struct Day12;
+
+#[derive(Clone)]
+pub struct Map {
+    heights: Grid<char>,
+    start: Point<usize>,
+    end: Point<usize>,
+    scores: Grid<Option<u32>>,
+}
+
+impl Map {
+    fn new(heights: Grid<char>) -> Result<Map, ParseError> {
+        let start = heights
+            .position(|&height| height == 'S')
+            .ok_or_else(|| ParseError::new("couldn't find the start 'S'"))?;
+        let end = heights
+            .position(|&height| height == 'E')
+            .ok_or_else(|| ParseError::new("couldn't find the end 'E'"))?;
+
+        let mut scores = Grid::new(heights.width(), heights.height(), None);
+        scores[end] = Some(0);
+        Ok(Map {
+            heights: heights.map(|&height| match height {
+                'S' => 'a',
+                'E' => 'z',
+                _ => height,
+            }),
+            start,
+            end,
+            scores,
+        })
+    }
+
+    fn calculate_scores(&mut self, to: Point<usize>) {
+        let to_score = self.scores[to].unwrap();
+
+        let surrounding: Vec<Point<usize>> = self.heights.neighbours(to).collect();
+
+        for from in surrounding {
+            let from_score = to_score + 1;
+
+            let current_from_score = self.scores[from];
+
+            if self.is_eligible_move(&from, &to) {
+                if current_from_score.is_some_and(|score| score <= from_score) {
+                    continue;
+                }
+
+                self.scores[from] = Some(from_score);
+                self.calculate_scores(from);
+            }
+        }
+    }
+
+    fn is_eligible_move(&self, from: &Point<usize>, to: &Point<usize>) -> bool {
+        let from = self.heights[*from];
+        let to = self.heights[*to];
+
+        (to as i32) - (from as i32) <= 1
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.scores.rows() {
+            for score in row {
+                write!(
+                    f,
+                    ".{:0>4}",
+                    score.map(|s| s.to_string()).unwrap_or("-".to_string())
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The scores spreading out from the end one step at a time, breadth first
+/// so every step adds the points one step further away.
+struct Search {
+    map: Map,
+    frontier: Vec<Point<usize>>,
+    distance: u32,
+}
+
+impl Search {
+    fn new(map: Map) -> Search {
+        let frontier = vec![map.end];
+
+        Search {
+            map,
+            frontier,
+            distance: 0,
+        }
+    }
+
+    /// The fewest steps from any point with the `height`, if one was reached.
+    fn closest(&self, height: char) -> Option<u32> {
+        self.map
+            .heights
+            .iter()
+            .filter(|(_, &h)| h == height)
+            .filter_map(|(point, _)| self.map.scores[point])
+            .min()
+    }
+}
+
+impl Simulation for Search {
+    fn step(&mut self) -> bool {
+        if self.frontier.is_empty() {
+            return false;
+        }
+
+        let mut next = vec![];
+        for &to in &self.frontier {
+            for from in self.map.heights.neighbours(to) {
+                if self.map.scores[from].is_none() && self.map.is_eligible_move(&from, &to) {
+                    self.map.scores[from] = Some(self.distance + 1);
+                    next.push(from);
+                }
+            }
+        }
+
+        self.frontier = next;
+        self.distance += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        let mut frame = String::new();
+        for (y, row) in self.map.scores.rows().enumerate() {
+            for (x, score) in row.iter().enumerate() {
+                let point = Point::new(x, y);
+                frame.push(if point == self.map.start {
+                    'S'
+                } else if point == self.map.end {
+                    'E'
+                } else if *score == Some(self.distance) {
+                    '#'
+                } else if score.is_some() {
+                    '.'
+                } else {
+                    self.map.heights[point]
+                });
+            }
+            frame.push('\n');
+        }
+
+        frame
+    }
+
+    fn caption(&self) -> String {
+        let steps = |steps: Option<u32>| steps.map_or("-".to_string(), |s| s.to_string());
+        format!(
+            "distance {}, fewest steps from S: {}, from any a: {}",
+            self.distance,
+            steps(self.map.scores[self.map.start]),
+            steps(self.closest('a'))
+        )
+    }
+}
+
+impl Solution for Day12 {
+    type Parsed = Map;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let heights = Grid::parse(input, |height| match height {
+            'a'..='z' | 'S' | 'E' => Ok(height),
+            _ => Err(format!(
+                "expected a height from 'a' to 'z', got '{}'",
+                height
+            )),
+        })?;
+
+        Map::new(heights)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Part1 {
+        let mut map = map.clone();
+        map.calculate_scores(map.end);
+
+        map.scores[map.start].unwrap()
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Part2 {
+        let mut map = map.clone();
+        map.calculate_scores(map.end);
+
+        map.heights
+            .iter()
+            .filter(|(_, &height)| height == 'a')
+            .filter_map(|(start, _)| map.scores[start])
+            .fold(u32::MAX, |min_steps, steps| min_steps.min(steps))
+    }
+}
+
+/// Spreads the scores out from the end, which answers both parts at once.
+pub fn simulate(input: &str, _part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Search::new(Day12::parse(input)?)))
+}
+
+/// Generates a map `size` wide, at least 26 so a path can climb from `a` to
+/// `z` one step at a time. The path is planted first, so there always is one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (width / 4).max(5);
+    let mut heights = Grid::new(width, height, 'a');
+    for point in heights.points().collect::<Vec<Point<usize>>>() {
+        heights[point] = (b'a' + rng.below(26) as u8) as char;
+    }
+
+    // Walk to a random row of each column, then step right
+    let mut path = vec![Point::new(0, rng.below(height))];
+    for x in 0..width {
+        let mut y = path.last().unwrap().y;
+        let target = rng.below(height);
+        while y != target {
+            y = if y < target { y + 1 } else { y - 1 };
+            path.push(Point::new(x, y));
+        }
+        if x + 1 < width {
+            path.push(Point::new(x + 1, y));
+        }
+    }
+
+    for (i, &point) in path.iter().enumerate() {
+        heights[point] = (b'a' + (25 * i / (path.len() - 1)) as u8) as char;
+    }
+    heights[path[0]] = 'S';
+    heights[*path.last().unwrap()] = 'E';
+
+    heights.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&parsed), 31);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&parsed), 29);
+    }
+}
@@ -0,0 +1,437 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::{
+    error::ParseError,
+    generate::Rng,
+    repl::{self, Session},
+    solution::Solution,
+};
+
+pub struct Day13;
+
+#[derive(Debug, Clone)]
+pub enum Input {
+    Value(u32),
+    List(Vec<Input>),
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = PacketParser {
+            line: s,
+            chars: s.chars().collect(),
+            position: 0,
+            depth: 0,
+        };
+
+        let packet = parser.list()?;
+        if parser.position < parser.chars.len() {
+            return Err(parser.error("unexpected characters after the packet"));
+        }
+
+        Ok(packet)
+    }
+}
+
+/// Recursive descent parser for a single packet line.
+struct PacketParser<'a> {
+    line: &'a str,
+    chars: Vec<char>,
+    position: usize,
+    /// Lists currently open, limited so nesting can't overflow the stack.
+    depth: usize,
+}
+
+impl PacketParser<'_> {
+    const MAX_DEPTH: usize = 256;
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::at(self.line, self.position, message)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    fn list(&mut self) -> Result<Input, ParseError> {
+        if self.depth == PacketParser::MAX_DEPTH {
+            let message = format!("lists nest deeper than {}", PacketParser::MAX_DEPTH);
+            return Err(self.error(&message));
+        }
+
+        self.depth += 1;
+        let list = self.items();
+        self.depth -= 1;
+
+        list
+    }
+
+    fn items(&mut self) -> Result<Input, ParseError> {
+        self.expect('[')?;
+
+        let mut data: Vec<Input> = Vec::new();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Input::List(data));
+        }
+
+        loop {
+            match self.peek() {
+                Some('[') => data.push(self.list()?),
+                Some(c) if c.is_ascii_digit() => data.push(self.value()?),
+                _ => return Err(self.error("expected a value or a list")),
+            }
+
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(Input::List(data));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Input, ParseError> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        let digits: String = self.chars[start..self.position].iter().collect();
+        digits.parse::<u32>().map(Input::Value).map_err(|e| {
+            ParseError::at(
+                self.line,
+                start,
+                format!("invalid number '{}': {}", digits, e),
+            )
+        })
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Value(number) => write!(f, "{}", number),
+            Input::List(inputs) => {
+                write!(f, "[")?;
+                for (i, input) in inputs.iter().enumerate() {
+                    write!(f, "{}", &input)?;
+                    if i != inputs.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                write!(f, "]")?;
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Input {
+    fn is_in_order(&self, other: &Input) -> Option<bool> {
+        match (self, other) {
+            (Input::Value(l), Input::Value(r)) => {
+                if l < r {
+                    Some(true)
+                } else if l > r {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            (Input::List(l), Input::List(r)) => {
+                let piecewise_result =
+                    l.iter()
+                        .zip(r.iter())
+                        .fold(None, |acc, (l_value, r_value)| {
+                            if acc.is_none() {
+                                l_value.is_in_order(r_value)
+                            } else {
+                                acc
+                            }
+                        });
+
+                if piecewise_result.is_some() {
+                    piecewise_result
+                } else {
+                    Input::Value(l.len() as u32).is_in_order(&Input::Value(r.len() as u32))
+                }
+            }
+            (Input::Value(_), Input::List(_)) => Input::List(vec![self.clone()]).is_in_order(other),
+            (Input::List(_), Input::Value(_)) => {
+                self.is_in_order(&Input::List(vec![other.clone()]))
+            }
+        }
+    }
+}
+
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Value(l0), Self::Value(r0)) => l0 == r0,
+            (Self::List(l0), Self::List(r0)) => l0 == r0,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Input {}
+impl PartialOrd for Input {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Input {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.is_in_order(other) {
+            Some(true) => Ordering::Less,
+            Some(false) => Ordering::Greater,
+            None => Ordering::Equal,
+        }
+    }
+}
+
+/// Looks up packets by their number, counted from 1 like the pairs.
+struct Packets {
+    pairs: Vec<(Input, Input)>,
+}
+
+impl Packets {
+    fn packet(&self, n: usize) -> Option<&Input> {
+        let (left, right) = self.pairs.get(n.checked_sub(1)? / 2)?;
+        Some(if n % 2 == 1 { left } else { right })
+    }
+}
+
+const COMMANDS: &[repl::Command] = &[
+    repl::Command {
+        name: "packet",
+        args: "<n>",
+        help: "the packet, counting from 1",
+    },
+    repl::Command {
+        name: "pair",
+        args: "<n>",
+        help: "the packets of the pair and whether they're in order",
+    },
+];
+
+impl Session for Packets {
+    fn commands(&self) -> &'static [repl::Command] {
+        COMMANDS
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "packet" => {
+                let n: usize = repl::arg(args, 0, "packet number")?;
+                self.packet(n)
+                    .map(|packet| packet.to_string())
+                    .ok_or_else(|| format!("there are {} packets", self.pairs.len() * 2))
+            }
+            "pair" => {
+                let n: usize = repl::arg(args, 0, "pair number")?;
+                let (left, right) = n
+                    .checked_sub(1)
+                    .and_then(|i| self.pairs.get(i))
+                    .ok_or_else(|| format!("there are {} pairs", self.pairs.len()))?;
+                let order = match left.is_in_order(right) {
+                    Some(true) => "in the right order",
+                    Some(false) => "in the wrong order",
+                    None => "equal, their order is undecided",
+                };
+                Ok(format!("{}\n{}\n{}", left, right, order))
+            }
+            _ => Err(format!("unknown command '{}'", command)),
+        }
+    }
+}
+
+pub fn explore(input: &str, _part: u8) -> Result<Box<dyn Session>, ParseError> {
+    Ok(Box::new(Packets {
+        pairs: Day13::parse(input)?,
+    }))
+}
+
+impl Solution for Day13 {
+    type Parsed = Vec<(Input, Input)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut packets = vec![];
+        for (i, line) in input.lines().enumerate() {
+            if !line.is_empty() {
+                packets.push(line.parse::<Input>().map_err(|e| e.offset(i))?);
+            }
+        }
+
+        if !packets.len().is_multiple_of(2) {
+            return Err(ParseError::new("the last packet doesn't have a pair"));
+        }
+
+        let pairs = packets
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect();
+
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Self::Part1 {
+        pairs
+            .iter()
+            .map(|(left, right)| left.is_in_order(right).unwrap())
+            .enumerate()
+            .map(|(i, in_order)| (i + 1, in_order))
+            .filter(|(_, in_order)| *in_order)
+            .map(|(i, _)| i)
+            .sum()
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Self::Part2 {
+        let divider1 = "[[2]]".parse::<Input>().unwrap();
+        let divider2 = "[[6]]".parse::<Input>().unwrap();
+
+        let mut packets = pairs
+            .iter()
+            .flat_map(|(left, right)| vec![left.clone(), right.clone()])
+            .chain(vec![divider1.clone(), divider2.clone()])
+            .collect::<Vec<Input>>();
+        packets.sort();
+
+        packets
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item == &&divider1 || item == &&divider2)
+            .map(|(i, _)| i + 1)
+            .product()
+    }
+}
+
+/// Generates `size` pairs of packets, never two equal ones as their order
+/// would be undecided.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    fn list(rng: &mut Rng, depth: usize) -> Input {
+        let len = rng.range(0..=4);
+        Input::List(
+            (0..len)
+                .map(|_| {
+                    if depth > 0 && rng.chance(0.3) {
+                        list(rng, depth - 1)
+                    } else {
+                        Input::Value(rng.range(0..=10) as u32)
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    let mut pairs = vec![];
+    while pairs.len() < size.max(1) {
+        let left = list(rng, 3);
+        let right = list(rng, 3);
+        if left.is_in_order(&right).is_some() {
+            pairs.push(format!("{}\n{}\n", left, right));
+        }
+    }
+
+    pairs.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&parsed), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&parsed), 140);
+    }
+
+    fn parse(s: &str) -> Input {
+        s.parse::<Input>().unwrap()
+    }
+
+    #[test]
+    fn parses_nested_lists() {
+        assert_eq!(parse("[]"), Input::List(vec![]));
+        assert_eq!(parse("[[]]"), Input::List(vec![Input::List(vec![])]));
+        assert_eq!(
+            parse("[1,[2,[3]]]"),
+            Input::List(vec![
+                Input::Value(1),
+                Input::List(vec![Input::Value(2), Input::List(vec![Input::Value(3)])]),
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_multi_digit_values() {
+        assert_eq!(
+            parse("[10,[100],7]"),
+            Input::List(vec![
+                Input::Value(10),
+                Input::List(vec![Input::Value(100)]),
+                Input::Value(7),
+            ])
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for line in EXAMPLE.lines().filter(|line| !line.is_empty()) {
+            assert_eq!(parse(line).to_string(), line);
+        }
+    }
+
+    #[test]
+    fn compares_mixed_types() {
+        assert_eq!(parse("[9]").is_in_order(&parse("[[8,7,6]]")), Some(false));
+        assert_eq!(
+            parse("[[4,4],4,4]").is_in_order(&parse("[[4,4],4,4,4]")),
+            Some(true)
+        );
+        assert_eq!(parse("[[1]]").is_in_order(&parse("[1]")), None);
+    }
+
+    #[test]
+    fn locates_malformed_packets() {
+        let column = |s: &str| s.parse::<Input>().unwrap_err().location.unwrap().column;
+
+        assert_eq!(column("[1,[2,3]"), 9);
+        assert_eq!(column("[1,,2]"), 4);
+        assert_eq!(column("[1]]"), 4);
+        assert_eq!(column("1"), 1);
+    }
+
+    #[test]
+    fn rejects_deeply_nested_packets() {
+        let nested = "[".repeat(100_000);
+        let error = nested.parse::<Input>().unwrap_err();
+
+        assert_eq!(error.location.unwrap().column, PacketParser::MAX_DEPTH + 1);
+    }
+}
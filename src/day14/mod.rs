@@ -0,0 +1,282 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::{
+    animate::Simulation,
+    error::{parse_lines, ParseError},
+    generate::Rng,
+    geometry,
+    solution::Solution,
+};
+
+pub struct Day14;
+
+type Point = geometry::Point<i32>;
+type Rect = geometry::Rect<i32>;
+
+#[derive(Clone)]
+pub struct Map {
+    sand_point: Point,
+    rocks: HashSet<Point>,
+    sand: HashSet<Point>,
+    bounds: Rect,
+}
+
+impl Map {
+    fn new(rocks: HashSet<Point>) -> Map {
+        let mut bounds = Rect::bounding(rocks.iter().copied()).unwrap();
+        bounds.encapsulate(&Point::new(500, 0));
+
+        Map {
+            sand_point: Point::new(500, 0),
+            rocks,
+            sand: HashSet::new(),
+            bounds,
+        }
+    }
+
+    fn occupied(&self, pos: Point) -> bool {
+        self.sand.contains(&pos) || self.rocks.contains(&pos)
+    }
+
+    fn spawn_sand(&mut self) {
+        let mut position = Some(self.sand_point);
+        let mut old_position = position;
+
+        loop {
+            position = self.simulate_step(position.unwrap());
+
+            if position.is_none() || position == old_position {
+                break;
+            } else {
+                old_position = position;
+            }
+        }
+
+        if let Some(position) = position {
+            self.sand.insert(position);
+        }
+    }
+
+    fn simulate_step(&self, pos: Point) -> Option<Point> {
+        let mut pos = self.find_projection_point(pos)?;
+
+        let down_left: Point = (-1, 1).into();
+        let down_right: Point = (1, 1).into();
+
+        if !self.occupied(pos + down_left) {
+            pos += down_left;
+        } else if !self.occupied(pos + down_right) {
+            pos += down_right;
+        }
+
+        Some(pos)
+    }
+
+    /// Adds the floor of part 2, two below the lowest rock and wide enough
+    /// for the sand to never fall past it.
+    fn add_floor(&mut self) {
+        let y = self.bounds.bottom + 2;
+        for x in -500..1500 {
+            let point: Point = (x, y).into();
+            self.rocks.insert(point);
+            self.bounds.encapsulate(&point);
+        }
+    }
+
+    fn find_projection_point(&self, pos: Point) -> Option<Point> {
+        for y in pos.y..=self.bounds.bottom {
+            if self.occupied((pos.x, y + 1).into()) {
+                return Some((pos.x, y).into());
+            }
+        }
+
+        None
+    }
+}
+
+impl Map {
+    fn draw(&self, f: &mut impl fmt::Write, view: &Rect) -> fmt::Result {
+        for y in view.top..=view.bottom {
+            for x in view.left..=view.right {
+                if self.sand_point == (x, y).into() {
+                    write!(f, "+")?;
+                } else if self.sand.contains(&(x, y).into()) {
+                    write!(f, "o")?;
+                } else if self.rocks.contains(&(x, y).into()) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.draw(f, &self.bounds)
+    }
+}
+
+/// Sand falling one unit at a time, until it falls into the abyss or, with
+/// a floor, blocks the source.
+struct Sandfall {
+    map: Map,
+    floor: bool,
+    /// The part of the map sand can reach, the floor is far wider.
+    view: Rect,
+}
+
+impl Sandfall {
+    fn new(mut map: Map, floor: bool) -> Sandfall {
+        let mut view = map.bounds;
+        if floor {
+            map.add_floor();
+            let depth = map.bounds.bottom;
+            view.encapsulate(&Point::new(500 - depth, depth));
+            view.encapsulate(&Point::new(500 + depth, depth));
+        }
+
+        Sandfall { map, floor, view }
+    }
+}
+
+impl Simulation for Sandfall {
+    fn step(&mut self) -> bool {
+        if self.floor && self.map.sand.contains(&self.map.sand_point) {
+            return false;
+        }
+
+        let n_sand = self.map.sand.len();
+        self.map.spawn_sand();
+        self.map.sand.len() != n_sand
+    }
+
+    fn frame(&self) -> String {
+        let mut frame = String::new();
+        self.map.draw(&mut frame, &self.view).unwrap();
+
+        frame
+    }
+
+    fn caption(&self) -> String {
+        format!("{} units of sand at rest", self.map.sand.len())
+    }
+}
+
+impl Solution for Day14 {
+    type Parsed = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let paths = parse_lines(input, |line| {
+            line.split(" -> ")
+                .map(|p| p.parse::<Point>().map_err(|e| e.within(line, p)))
+                .collect::<Result<Vec<Point>, ParseError>>()
+        })?;
+
+        let rocks: HashSet<Point> = paths
+            .iter()
+            .flat_map(|trace_points| {
+                trace_points
+                    .windows(2)
+                    .flat_map(|window| {
+                        let p1 = &window[0];
+                        let p2 = &window[1];
+                        let min: Point = (p1.x.min(p2.x), p1.y.min(p2.y)).into();
+                        let max: Point = (p1.x.max(p2.x), p1.y.max(p2.y)).into();
+                        (min.x..=max.x)
+                            .flat_map(move |x| (min.y..=max.y).map(move |y| Point::from((x, y))))
+                    })
+                    .collect::<Vec<Point>>()
+            })
+            .collect();
+
+        if rocks.is_empty() {
+            return Err(ParseError::new("there are no rock paths"));
+        }
+
+        Ok(Map::new(rocks))
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Part1 {
+        let mut sandfall = Sandfall::new(map.clone(), false);
+        while sandfall.step() {}
+
+        sandfall.map.sand.len()
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Part2 {
+        let mut sandfall = Sandfall::new(map.clone(), true);
+        while sandfall.step() {}
+
+        sandfall.map.sand.len()
+    }
+}
+
+/// Pours the sand of the part, onto the floor for part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Sandfall::new(Day14::parse(input)?, part != 1)))
+}
+
+/// Generates `size` rock paths spreading further out and down as `size`
+/// grows, staying within the floor added by part 2.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let spread = (size * 2).clamp(10, 400);
+    let depth = (size + 10).min(400);
+
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let mut x = 500 - spread + rng.below(2 * spread + 1);
+        let mut y = rng.range(2..=depth);
+        let mut points = vec![format!("{},{}", x, y)];
+
+        for segment in 0..rng.range(1..=4) {
+            let len = rng.range(1..=8);
+            let forward = rng.chance(0.5);
+            if segment % 2 == 0 {
+                x = if forward { x + len } else { x - len };
+            } else {
+                y = if forward {
+                    y + len
+                } else {
+                    y.saturating_sub(len)
+                }
+                .clamp(2, depth);
+            }
+            points.push(format!("{},{}", x, y));
+        }
+
+        input.push_str(&points.join(" -> "));
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&parsed), 24);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part2(&parsed), 93);
+    }
+}
@@ -0,0 +1,233 @@
+use std::str::FromStr;
+
+use crate::{
+    error::{parse_lines, ParseError},
+    generate::Rng,
+    solution::Solution,
+};
+
+pub struct Day2;
+
+#[derive(Eq, PartialEq, Clone, Copy)]
+enum Move {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+impl Move {
+    fn score(&self) -> usize {
+        match self {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissor => 3,
+        }
+    }
+}
+
+impl Move {
+    fn calculate_move(other_move: Move, desired_result: RoundResult) -> Move {
+        match desired_result {
+            RoundResult::Draw => other_move,
+            RoundResult::Win => other_move.winning_move(),
+            RoundResult::Loose => other_move.loosing_move(),
+        }
+    }
+
+    fn winning_move(&self) -> Move {
+        match self {
+            Move::Paper => Move::Scissor,
+            Move::Scissor => Move::Rock,
+            Move::Rock => Move::Paper,
+        }
+    }
+
+    fn loosing_move(&self) -> Move {
+        match self {
+            Move::Paper => Move::Rock,
+            Move::Scissor => Move::Paper,
+            Move::Rock => Move::Scissor,
+        }
+    }
+}
+
+impl TryFrom<&str> for Move {
+    type Error = String;
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "A" => Ok(Move::Rock),
+            "B" => Ok(Move::Paper),
+            "C" => Ok(Move::Scissor),
+            _ => Err(format!("Couldn't convert {} to a Move", c)),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq)]
+enum RoundResult {
+    Win,
+    Loose,
+    Draw,
+}
+
+impl RoundResult {
+    fn score(&self) -> usize {
+        match self {
+            RoundResult::Loose => 0,
+            RoundResult::Draw => 3,
+            RoundResult::Win => 6,
+        }
+    }
+}
+
+/// The second column of the strategy guide, which part 1 reads as my move and
+/// part 2 as the desired result.
+#[derive(Eq, PartialEq, Clone, Copy)]
+enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    fn as_move(&self) -> Move {
+        match self {
+            Response::X => Move::Rock,
+            Response::Y => Move::Paper,
+            Response::Z => Move::Scissor,
+        }
+    }
+
+    fn as_result(&self) -> RoundResult {
+        match self {
+            Response::X => RoundResult::Loose,
+            Response::Y => RoundResult::Draw,
+            Response::Z => RoundResult::Win,
+        }
+    }
+}
+
+impl TryFrom<&str> for Response {
+    type Error = String;
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "X" => Ok(Response::X),
+            "Y" => Ok(Response::Y),
+            "Z" => Ok(Response::Z),
+            _ => Err(format!("Couldn't convert {} to a Response", c)),
+        }
+    }
+}
+
+struct Round {
+    other_move: Move,
+    my_move: Move,
+}
+
+impl Round {
+    fn result(&self) -> RoundResult {
+        if self.my_move == self.other_move {
+            RoundResult::Draw
+        } else if self.other_move == Move::Rock && self.my_move == Move::Paper
+            || self.other_move == Move::Paper && self.my_move == Move::Scissor
+            || self.other_move == Move::Scissor && self.my_move == Move::Rock
+        {
+            RoundResult::Win
+        } else {
+            RoundResult::Loose
+        }
+    }
+
+    fn score(&self) -> usize {
+        let move_score = self.my_move.score();
+        let match_score = self.result().score();
+
+        move_score + match_score
+    }
+}
+
+pub struct Strategy {
+    other_move: Move,
+    response: Response,
+}
+
+impl FromStr for Strategy {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (other_move, response) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(s, s.len(), "expected a move and a response"))?;
+
+        Ok(Strategy {
+            other_move: Move::try_from(other_move)
+                .map_err(|e| ParseError::span(s, other_move, e))?,
+            response: Response::try_from(response).map_err(|e| ParseError::span(s, response, e))?,
+        })
+    }
+}
+
+impl Solution for Day2 {
+    type Parsed = Vec<Strategy>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(strategies: &Self::Parsed) -> Self::Part1 {
+        strategies
+            .iter()
+            .map(|strategy| Round {
+                other_move: strategy.other_move,
+                my_move: strategy.response.as_move(),
+            })
+            .map(|round| round.score())
+            .sum()
+    }
+
+    fn part2(strategies: &Self::Parsed) -> Self::Part2 {
+        strategies
+            .iter()
+            .map(|strategy| Round {
+                other_move: strategy.other_move,
+                my_move: Move::calculate_move(strategy.other_move, strategy.response.as_result()),
+            })
+            .map(|round| round.score())
+            .sum()
+    }
+}
+
+/// Generates `size` rounds, at least the one the parser needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let other_move = rng.choose(&['A', 'B', 'C']);
+        let response = rng.choose(&['X', 'Y', 'Z']);
+        input.push_str(&format!("{} {}\n", other_move, response));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&parsed), 15);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&parsed), 12);
+    }
+}
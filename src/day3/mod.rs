@@ -0,0 +1,179 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+    error::{parse_lines, ParseError},
+    generate::Rng,
+    solution::Solution,
+};
+
+pub struct Day3;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+struct Item {
+    item: char,
+}
+
+impl From<char> for Item {
+    fn from(c: char) -> Self {
+        Item { item: c }
+    }
+}
+
+impl Item {
+    fn priority(&self) -> u32 {
+        if self.item.is_ascii_lowercase() {
+            (self.item as u32) - ('a' as u32) + 1
+        } else {
+            (self.item as u32) - ('A' as u32) + 27
+        }
+    }
+}
+
+pub struct Rugsack {
+    compartments: (Vec<Item>, Vec<Item>),
+}
+
+impl Rugsack {
+    fn iter(&self) -> std::iter::Chain<std::slice::Iter<'_, Item>, std::slice::Iter<'_, Item>> {
+        self.compartments.0.iter().chain(self.compartments.1.iter())
+    }
+
+    fn find_misplaced(&self) -> &Item {
+        self.compartments
+            .0
+            .iter()
+            .find(|item| {
+                self.compartments
+                    .1
+                    .iter()
+                    .any(|other_item| item == &other_item)
+            })
+            .unwrap()
+    }
+}
+
+impl FromStr for Rugsack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(column) = s.chars().position(|c| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(s, column, "items need to be letters"));
+        }
+        if !s.len().is_multiple_of(2) {
+            let message = "the items can't be split into two equal compartments";
+            return Err(ParseError::at(s, s.len(), message));
+        }
+
+        let mut compartments = (vec![], vec![]);
+
+        let compartment_size = s.len() / 2;
+
+        s.chars().enumerate().for_each(|(index, item)| {
+            let compartment = if index >= compartment_size {
+                &mut compartments.0
+            } else {
+                &mut compartments.1
+            };
+
+            compartment.push(item.into());
+        });
+
+        Ok(Rugsack { compartments })
+    }
+}
+
+fn find_common(elf_a: &Rugsack, elf_b: &Rugsack, elf_c: &Rugsack) -> Item {
+    let items_a: HashSet<&Item> = HashSet::from_iter(elf_a.iter());
+    let items_b: HashSet<&Item> = HashSet::from_iter(elf_b.iter());
+    let items_c: HashSet<&Item> = HashSet::from_iter(elf_c.iter());
+
+    let common_ab: HashSet<&Item> = HashSet::from_iter(items_a.intersection(&items_b).copied());
+    let mut common_abc = common_ab.intersection(&items_c).copied();
+
+    *common_abc.next().unwrap()
+}
+
+impl Solution for Day3 {
+    type Parsed = Vec<Rugsack>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(rugsacks: &Self::Parsed) -> Self::Part1 {
+        rugsacks
+            .iter()
+            .map(|rugsack| *rugsack.find_misplaced())
+            .map(|misplaced| misplaced.priority())
+            .sum()
+    }
+
+    fn part2(rugsacks: &Self::Parsed) -> Self::Part2 {
+        let mut commons = vec![];
+
+        for i in (0..rugsacks.len()).step_by(3) {
+            let elf_a = rugsacks.get(i).unwrap();
+            let elf_b = rugsacks.get(i + 1).unwrap();
+            let elf_c = rugsacks.get(i + 2).unwrap();
+
+            let common = find_common(elf_a, elf_b, elf_c);
+            commons.push(common);
+        }
+
+        commons.iter().map(|i| i.priority()).sum()
+    }
+}
+
+/// Generates `size` rugsacks rounded up to whole groups. Every rugsack has
+/// exactly one item in both compartments and every group exactly one badge.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut input = String::new();
+
+    for _ in 0..size.div_ceil(3).max(1) {
+        rng.shuffle(&mut letters);
+        let (badge, rest) = letters.split_first().unwrap();
+
+        // Each elf of the group gets its own letters, split between both
+        // compartments, so only the badge and the misplaced item are shared.
+        for pool in rest.chunks(17) {
+            let (left, right) = pool.split_at(8);
+            let misplaced = *rng.choose(pool);
+            let len = rng.range(4..=16);
+
+            let mut first: Vec<char> = (0..len).map(|_| *rng.choose(left)).collect();
+            let mut second: Vec<char> = (0..len).map(|_| *rng.choose(right)).collect();
+            first[0] = misplaced;
+            first[1] = *badge;
+            second[0] = misplaced;
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+
+            input.extend(first.iter().chain(second.iter()));
+            input.push('\n');
+        }
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&parsed), 157);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&parsed), 70);
+    }
+}
@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use crate::{
+    error::{parse_lines, parse_number, ParseError},
+    generate::Rng,
+    solution::Solution,
+};
+
+pub struct Day4;
+
+/// Parses a section assignment like `2-4`, found in `line`.
+fn to_range(line: &str, s: &str) -> Result<Range<usize>, ParseError> {
+    let (start_str, end_str) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::span(line, s, "expected a range like '2-4'"))?;
+
+    let start: usize = parse_number(line, start_str)?;
+    let end: usize = parse_number(line, end_str)?;
+    if end < start {
+        return Err(ParseError::span(line, s, "the range ends before it starts"));
+    }
+
+    Ok(Range {
+        start,
+        end: end + 1,
+    })
+}
+
+fn to_pair(line: &str) -> Result<(Range<usize>, Range<usize>), ParseError> {
+    let (first_range, second_range) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(line, line.len(), "expected two ranges"))?;
+
+    Ok((to_range(line, first_range)?, to_range(line, second_range)?))
+}
+
+fn is_contained(r1: &Range<usize>, r2: &Range<usize>) -> bool {
+    let contained_in_r1 = r1.contains(&r2.start) && r1.contains(&(r2.end - 1));
+    let contained_in_r2 = r2.contains(&r1.start) && r2.contains(&(r1.end - 1));
+
+    contained_in_r1 || contained_in_r2
+}
+
+fn is_overlapping(r1: &Range<usize>, r2: &Range<usize>) -> bool {
+    let overlapping_in_r1 = r1.contains(&r2.start) || r1.contains(&(r2.end - 1));
+    let overlapping_in_r2 = r2.contains(&r1.start) || r2.contains(&(r1.end - 1));
+
+    overlapping_in_r1 || overlapping_in_r2
+}
+
+impl Solution for Day4 {
+    type Parsed = Vec<(Range<usize>, Range<usize>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, to_pair)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Self::Part1 {
+        pairs.iter().filter(|(r1, r2)| is_contained(r1, r2)).count()
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Self::Part2 {
+        pairs
+            .iter()
+            .filter(|(r1, r2)| is_overlapping(r1, r2))
+            .count()
+    }
+}
+
+/// Generates `size` pairs of section assignments, at least the one the parser
+/// needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.range(1..=99);
+        let end = rng.range(start..=99);
+        format!("{}-{}", start, end)
+    };
+
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let first = range(rng);
+        let second = range(rng);
+        input.push_str(&format!("{},{}\n", first, second));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&parsed), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&parsed), 4);
+    }
+}
@@ -0,0 +1,353 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+use crate::{
+    error::{parse_lines, parse_number, ParseError},
+    generate::Rng,
+    solution::Solution,
+};
+
+pub struct Day5;
+
+#[derive(Debug, Clone)]
+struct Crate {
+    label: String,
+}
+
+impl FromStr for Crate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref re: Regex = Regex::new(r"^\[([A-Z])\]$").unwrap();
+        }
+
+        let captures = re
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, 0, "expected a crate like '[A]'"))?;
+
+        Ok(Crate {
+            label: captures[1].to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stacks {
+    stacks: Vec<Vec<Crate>>,
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stacks = vec![];
+
+        let widest_line = s
+            .lines()
+            .map(|line| line.len())
+            .max()
+            .ok_or_else(|| ParseError::new("the stacks drawing is empty"))?;
+        // 3 characters + 1 space per box, except for the last one, whose
+        // trailing space may have been trimmed
+        let total_columns = (widest_line + 1) / 4;
+        for _ in 0..total_columns {
+            stacks.push(vec![]);
+        }
+
+        let n_lines = s.lines().count();
+        let mut rows: Vec<Vec<Option<Crate>>> = vec![];
+        for (y, line) in s.lines().enumerate().take(n_lines - 1) {
+            let mut row: Vec<Option<Crate>> = vec![];
+            for i in 0..total_columns {
+                let cell = line.get((i * 4)..(i * 4 + 3).min(line.len())).unwrap_or("");
+                if cell.trim().is_empty() {
+                    row.push(None);
+                    continue;
+                }
+
+                let parsed = cell
+                    .parse::<Crate>()
+                    .map_err(|e| e.within(line, cell).offset(y))?;
+                row.push(Some(parsed));
+            }
+
+            rows.push(row);
+        }
+
+        for row in rows.into_iter().rev() {
+            for (i, column) in row.into_iter().enumerate() {
+                let stack = stacks.get_mut(i).unwrap();
+
+                if let Some(cell) = column {
+                    stack.push(cell);
+                }
+            }
+        }
+
+        Ok(Stacks { stacks })
+    }
+}
+
+#[derive(Debug)]
+struct Move {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref re: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        }
+
+        let captures = re
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, 0, "expected a move like 'move 1 from 2 to 3'"))?;
+
+        let amount = parse_number(s, &captures[1])?;
+        let from = parse_number(s, &captures[2])?;
+        let to = parse_number(s, &captures[3])?;
+
+        Ok(Move { amount, from, to })
+    }
+}
+
+impl Stacks {
+    /// The labels of the crates on top of the stacks, skipping empty ones.
+    fn top_labels(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .map(|c| c.label.clone())
+            .collect::<String>()
+    }
+
+    /// Takes the `amount` crates on top of the stack `from`, bottom first.
+    fn take(&mut self, from: usize, amount: usize) -> Vec<Crate> {
+        let stack = &mut self.stacks[from - 1];
+        stack.split_off(stack.len() - amount)
+    }
+}
+
+#[derive(Debug)]
+pub struct Procedure {
+    stacks: Stacks,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day5 {
+    type Parsed = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = input.replace('\r', "");
+
+        let (state, moves) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new("expected the stacks and the moves separated by an empty line")
+        })?;
+
+        let stacks = state.parse::<Stacks>()?;
+        let moves_offset = state.lines().count() + 1;
+        let n_stacks = stacks.stacks.len();
+        // Replayed to check every move has enough crates to take
+        let mut heights: Vec<usize> = stacks.stacks.iter().map(Vec::len).collect();
+        let moves: Vec<Move> = parse_lines(moves, |line| {
+            let m = line.parse::<Move>()?;
+            if let Some(n) = [m.from, m.to].into_iter().find(|&n| n == 0 || n > n_stacks) {
+                let message = format!("there's no stack {}, there are {}", n, n_stacks);
+                return Err(ParseError::at(line, 0, message));
+            }
+            if m.amount > heights[m.from - 1] {
+                let message = format!(
+                    "can't move {} crates, stack {} has {}",
+                    m.amount,
+                    m.from,
+                    heights[m.from - 1]
+                );
+                return Err(ParseError::at(line, 0, message));
+            }
+
+            heights[m.from - 1] -= m.amount;
+            heights[m.to - 1] += m.amount;
+            Ok(m)
+        })
+        .map_err(|e| e.offset(moves_offset))?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Self::Parsed) -> Self::Part1 {
+        let mut stacks = procedure.stacks.clone();
+
+        for m in &procedure.moves {
+            // One crate at a time, so the last one taken ends up at the bottom
+            let moved = stacks.take(m.from, m.amount);
+            stacks.stacks[m.to - 1].extend(moved.into_iter().rev());
+        }
+
+        stacks.top_labels()
+    }
+
+    fn part2(procedure: &Self::Parsed) -> Self::Part2 {
+        let mut stacks = procedure.stacks.clone();
+
+        for m in &procedure.moves {
+            let moved = stacks.take(m.from, m.amount);
+            stacks.stacks[m.to - 1].extend(moved);
+        }
+
+        stacks.top_labels()
+    }
+}
+
+/// Generates a drawing of up to 9 stacks followed by `size` moves, at least the
+/// one the parser needs. Moves never empty a stack, so every stack has a crate
+/// on top at the end.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n_stacks = rng.range(3..=9);
+    let stacks: Vec<Vec<char>> = (0..n_stacks)
+        .map(|_| {
+            let height = rng.range(2..=8);
+            (0..height)
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let mut input = String::new();
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(label) => format!("[{}]", label),
+                None => "   ".to_string(),
+            })
+            .collect();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+    let labels: Vec<String> = (1..=n_stacks).map(|i| format!(" {} ", i)).collect();
+    input.push_str(&labels.join(" "));
+    input.push_str("\n\n");
+
+    // There are at least twice as many crates as stacks, so there's always a
+    // stack with a crate to spare.
+    let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.len()).collect();
+    for _ in 0..size.max(1) {
+        let movable: Vec<usize> = (0..n_stacks).filter(|&i| heights[i] > 1).collect();
+        let from = *rng.choose(&movable);
+        let to = (from + rng.range(1..=n_stacks - 1)) % n_stacks;
+        let amount = rng.range(1..=heights[from] - 1);
+
+        heights[from] -= amount;
+        heights[to] += amount;
+        input.push_str(&format!(
+            "move {} from {} to {}\n",
+            amount,
+            from + 1,
+            to + 1
+        ));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&parsed), "CMZ");
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&parsed), "MCD");
+    }
+
+    #[test]
+    fn parses_stack_drawing() {
+        let stacks = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 "
+            .parse::<Stacks>()
+            .unwrap();
+
+        let labels: Vec<String> = stacks
+            .stacks
+            .iter()
+            .map(|stack| stack.iter().map(|c| c.label.clone()).collect())
+            .collect();
+        assert_eq!(labels, vec!["ZN", "MCD", "P"]);
+    }
+
+    #[test]
+    fn parses_stack_drawing_with_empty_stack() {
+        let stacks = "[A]     [C]\n 1   2   3 ".parse::<Stacks>().unwrap();
+
+        assert_eq!(stacks.stacks.len(), 3);
+        assert!(stacks.stacks[1].is_empty());
+        assert_eq!(stacks.stacks[2][0].label, "C");
+    }
+
+    #[test]
+    fn parses_move() {
+        let m = "move 12 from 3 to 10".parse::<Move>().unwrap();
+
+        assert_eq!((m.amount, m.from, m.to), (12, 3, 10));
+    }
+
+    #[test]
+    fn rejects_invalid_crate() {
+        assert!("   ".parse::<Crate>().is_err());
+        assert!("[a]".parse::<Crate>().is_err());
+    }
+
+    #[test]
+    fn locates_invalid_crates_in_the_drawing() {
+        let input = EXAMPLE.replacen("[Z]", "[z]", 1);
+        let error = Day5::parse(&input).unwrap_err();
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (3, 1));
+        assert_eq!(location.text, "[z] [M] [P]");
+    }
+
+    #[test]
+    fn rejects_move_to_missing_stack() {
+        let input = EXAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+        let error = Day5::parse(&input).unwrap_err();
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (9, 1));
+        assert_eq!(location.text, "move 1 from 1 to 4");
+    }
+
+    #[test]
+    fn rejects_moves_taking_too_many_crates() {
+        let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+        let error = Day5::parse(&input).unwrap_err();
+
+        assert_eq!(error.message, "can't move 4 crates, stack 1 has 3");
+        assert_eq!(error.location.unwrap().line, 7);
+    }
+
+    #[test]
+    fn moves_crates_onto_their_own_stack_and_skips_empty_ones() {
+        let input = "[A]    \n[B] [C]\n 1   2 \n\nmove 2 from 1 to 1\nmove 1 from 2 to 1\n";
+        let parsed = Day5::parse(input).unwrap();
+
+        assert_eq!(Day5::part1(&parsed), "C");
+        assert_eq!(Day5::part2(&parsed), "C");
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use crate::{error::ParseError, generate::Rng, solution::Solution};
+
+pub struct Day6;
+
+fn is_unique_chars(s: &str) -> bool {
+    let set: HashSet<char> = HashSet::from_iter(s.chars());
+
+    s.len() == set.len()
+}
+
+/// The number of characters read when the last `n_distinct` characters were
+/// all different, `None` if they never are.
+fn find_marker(input: &str, n_distinct: usize) -> Option<usize> {
+    (n_distinct..=input.len()).find(|&end| is_unique_chars(&input[end - n_distinct..end]))
+}
+
+/// Where the start-of-packet and start-of-message markers end.
+#[derive(Debug)]
+pub struct Markers {
+    packet: usize,
+    message: usize,
+}
+
+impl Solution for Day6 {
+    type Parsed = Markers;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines = input.lines();
+        let datastream = lines.next().unwrap_or("");
+
+        if let Some(column) = datastream.chars().position(|c| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(
+                datastream,
+                column,
+                "expected a lowercase letter",
+            ));
+        }
+        if let Some((i, line)) = lines.enumerate().find(|(_, line)| !line.is_empty()) {
+            let message = "expected the datastream on a single line";
+            return Err(ParseError::at(line, 0, message).offset(i + 1));
+        }
+
+        // Reported where the search for the marker gave up
+        let marker = |n_distinct| {
+            find_marker(datastream, n_distinct).ok_or_else(|| {
+                let message = format!("expected a marker of {} different letters", n_distinct);
+                ParseError::at(datastream, datastream.len(), message)
+            })
+        };
+
+        Ok(Markers {
+            packet: marker(4)?,
+            message: marker(14)?,
+        })
+    }
+
+    fn part1(markers: &Self::Parsed) -> Self::Part1 {
+        markers.packet
+    }
+
+    fn part2(markers: &Self::Parsed) -> Self::Part2 {
+        markers.message
+    }
+}
+
+/// Generates a datastream of `size` characters. The noise only uses three
+/// letters, so the markers are the ones planted in it, which are sometimes
+/// left out and don't fit in short streams.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut letters);
+
+    let mut datastream: Vec<char> = (0..size).map(|_| *rng.choose(&letters[..3])).collect();
+
+    if size >= 4 && rng.chance(0.9) {
+        let packet_marker = rng.range(0..=size - 4);
+        datastream[packet_marker..packet_marker + 4].copy_from_slice(&letters[3..7]);
+    }
+    if size >= 14 && rng.chance(0.9) {
+        let message_marker = rng.range(0..=size - 14);
+        datastream[message_marker..message_marker + 14].copy_from_slice(&letters[7..21]);
+    }
+
+    datastream.into_iter().collect::<String>() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&parsed), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&parsed), 19);
+    }
+
+    #[test]
+    fn finds_markers() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (input, packet, message) in examples {
+            let parsed = Day6::parse(input).unwrap();
+            assert_eq!(Day6::part1(&parsed), packet, "{}", input);
+            assert_eq!(Day6::part2(&parsed), message, "{}", input);
+        }
+    }
+
+    #[test]
+    fn finds_markers_ending_the_stream() {
+        assert_eq!(find_marker("abcd", 4), Some(4));
+        assert_eq!(find_marker("aaabcd", 4), Some(6));
+
+        let parsed = Day6::parse("aaabcdefghijklmn\n").unwrap();
+        assert_eq!((Day6::part1(&parsed), Day6::part2(&parsed)), (6, 16));
+    }
+
+    #[test]
+    fn rejects_streams_without_markers() {
+        let error = Day6::parse("abc\n").unwrap_err();
+        assert_eq!(error.message, "expected a marker of 4 different letters");
+        assert_eq!(error.location.unwrap().column, 4);
+
+        let error = Day6::parse("abcdabcdabcdabcd").unwrap_err();
+        assert_eq!(error.message, "expected a marker of 14 different letters");
+        assert_eq!(find_marker("", 4), None);
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{parse_number, ParseError},
+    generate::Rng,
+    repl::{self, Session},
+    solution::Solution,
+};
+
+pub struct Day7;
+
+#[derive(Clone, Debug)]
+struct Directory {
+    parent: Option<String>,
+    name: String,
+    directories: Vec<String>,
+    files: Vec<String>,
+}
+
+impl Directory {
+    fn new<T: ToString>(name: T, parent: Option<String>) -> Directory {
+        Directory {
+            parent,
+            name: name.to_string(),
+            directories: vec![],
+            files: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+impl File {
+    fn new<T: ToString>(name: T, size: usize) -> File {
+        File {
+            name: name.to_string(),
+            size,
+        }
+    }
+}
+
+fn total_size(
+    dir: String,
+    dir_index: &HashMap<String, Directory>,
+    self_size: &HashMap<String, usize>,
+) -> usize {
+    let total_size = dir_index
+        .get(&dir)
+        .unwrap()
+        .directories
+        .iter()
+        .map(|dir| total_size(dir.clone(), dir_index, self_size))
+        .sum::<usize>()
+        + self_size.get(&dir.clone()).unwrap();
+
+    total_size
+}
+
+pub struct FileSystem {
+    dir_index: HashMap<String, Directory>,
+    self_sizes: HashMap<String, usize>,
+}
+
+impl FileSystem {
+    fn directories(&self) -> Vec<String> {
+        self.dir_index
+            .values()
+            .map(|dir| dir.name.clone())
+            .collect::<Vec<String>>()
+    }
+
+    fn total_size(&self, dir: String) -> usize {
+        total_size(dir, &self.dir_index, &self.self_sizes)
+    }
+}
+
+impl Solution for Day7 {
+    type Parsed = FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut dir_index: HashMap<String, Directory> = HashMap::new();
+        let mut file_index: HashMap<String, File> = HashMap::new();
+        let root = Directory::new("/", None);
+        dir_index.insert(root.name.clone(), root);
+
+        let mut current: String = dir_index.get("/").unwrap().name.clone();
+
+        for (i, line) in input.lines().enumerate() {
+            if let Some(command) = line.strip_prefix("$ ") {
+                if let Some(next) = command.strip_prefix("cd ") {
+                    // dbg!(&current, &line);
+                    if next == "/" {
+                        current = "/".to_string();
+                    } else if next == ".." {
+                        let current_dir = dir_index.get(&current).unwrap();
+                        current = current_dir.parent.clone().ok_or_else(|| {
+                            ParseError::span(line, next, "the root directory has no parent")
+                                .offset(i)
+                        })?;
+                    } else {
+                        current = current.clone() + next + "/";
+                        if !dir_index.contains_key(&current) {
+                            let message = "the directory wasn't listed before";
+                            return Err(ParseError::span(line, next, message).offset(i));
+                        }
+                    }
+                } else if command != "ls" {
+                    let message = "expected a 'cd' or 'ls' command";
+                    return Err(ParseError::span(line, command, message).offset(i));
+                }
+            } else if let Some(dir) = line.strip_prefix("dir ") {
+                let name = current.clone() + dir + "/";
+
+                let new = Directory::new(name, Some(current.clone()));
+                dir_index.insert(new.name.clone(), new.clone());
+                dir_index
+                    .get_mut(&current)
+                    .unwrap()
+                    .directories
+                    .push(new.name.clone());
+            } else {
+                let (size, name) = line.split_once(' ').ok_or_else(|| {
+                    ParseError::at(line, 0, "expected a command, a directory or a file").offset(i)
+                })?;
+                let size = parse_number(line, size).map_err(|e| e.offset(i))?;
+                let name = current.clone() + name + "/";
+                let file = File::new(name, size);
+                file_index.insert(file.name.clone(), file.clone());
+                dir_index
+                    .get_mut(&current)
+                    .unwrap()
+                    .files
+                    .push(file.name.clone());
+            }
+        }
+
+        let mut self_sizes: HashMap<String, usize> = HashMap::new();
+        for dir in dir_index.values() {
+            let self_size: usize = dir
+                .files
+                .iter()
+                .map(|file| file_index.get(file).unwrap().size)
+                .sum();
+
+            self_sizes.insert(dir.name.clone(), self_size);
+        }
+
+        Ok(FileSystem {
+            dir_index,
+            self_sizes,
+        })
+    }
+
+    fn part1(fs: &Self::Parsed) -> Self::Part1 {
+        fs.directories()
+            .iter()
+            .map(|dir| (dir.clone(), fs.total_size(dir.clone())))
+            .filter(|(_, total_size)| total_size <= &100000)
+            .map(|(_, total_size)| total_size)
+            .sum::<usize>()
+    }
+
+    fn part2(fs: &Self::Parsed) -> Self::Part2 {
+        let total_space: usize = 70000000;
+        let required_space: usize = 30000000;
+
+        let space_left = total_space - fs.total_size("/".to_string());
+        let to_free_up = required_space - space_left;
+
+        let mut dirs = fs
+            .directories()
+            .iter()
+            .map(|dir| fs.total_size(dir.clone()))
+            .collect::<Vec<usize>>();
+
+        dirs.sort();
+
+        *dirs
+            .iter()
+            .find(|folder_size| folder_size > &&to_free_up)
+            .unwrap()
+    }
+}
+
+/// Browses the directories found by the transcript.
+struct Explorer {
+    fs: FileSystem,
+}
+
+impl Explorer {
+    /// The directory at `path`, with or without the slashes around it.
+    fn directory(&self, path: Option<&&str>) -> Result<&Directory, String> {
+        let path = path.map_or("", |path| path.trim_matches('/'));
+        let name = if path.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/{}/", path)
+        };
+
+        self.fs
+            .dir_index
+            .get(&name)
+            .ok_or_else(|| format!("no directory {}", name))
+    }
+
+    /// The directory and everything below it, with their total sizes.
+    fn du(&self, dir: &Directory) -> Vec<(usize, String)> {
+        let mut sizes = vec![(self.fs.total_size(dir.name.clone()), dir.name.clone())];
+        for child in &dir.directories {
+            sizes.extend(self.du(&self.fs.dir_index[child]));
+        }
+        sizes
+    }
+}
+
+const COMMANDS: &[repl::Command] = &[
+    repl::Command {
+        name: "du",
+        args: "[dir]",
+        help: "total size of the directory and of those below it",
+    },
+    repl::Command {
+        name: "ls",
+        args: "[dir]",
+        help: "directories and files in the directory",
+    },
+];
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [repl::Command] {
+        COMMANDS
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let dir = self.directory(args.first())?;
+        let lines: Vec<String> = match command {
+            "du" => {
+                let sizes = self.du(dir);
+                let width = sizes[0].0.to_string().len();
+                sizes
+                    .into_iter()
+                    .map(|(size, name)| format!("{:>width$}  {}", size, name))
+                    .collect()
+            }
+            "ls" => {
+                let name = |path: &String| path[dir.name.len()..].to_string();
+                let mut names: Vec<String> = dir.directories.iter().map(name).collect();
+                names.extend(
+                    dir.files
+                        .iter()
+                        .map(|file| name(file).trim_end_matches('/').to_string()),
+                );
+                names
+            }
+            _ => return Err(format!("unknown command '{}'", command)),
+        };
+
+        Ok(lines.join("\n"))
+    }
+
+    fn complete(&self, _command: &str, arg: &str) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .fs
+            .dir_index
+            .keys()
+            .filter(|name| name.starts_with(arg))
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+pub fn explore(input: &str, _part: u8) -> Result<Box<dyn Session>, ParseError> {
+    Ok(Box::new(Explorer {
+        fs: Day7::parse(input)?,
+    }))
+}
+
+/// Generates the transcript of exploring `size` directories. The file sizes
+/// are scaled so the disk is between 40M and 70M full, as part 2 expects.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n_dirs = size.max(1);
+    let mut children: Vec<Vec<usize>> = vec![vec![]; n_dirs];
+    for dir in 1..n_dirs {
+        children[rng.below(dir)].push(dir);
+    }
+
+    let mut files: Vec<Vec<usize>> = (0..n_dirs)
+        .map(|_| {
+            let n_files = rng.range(0..=4);
+            (0..n_files).map(|_| rng.range(1..=300000)).collect()
+        })
+        .collect();
+    files[0].push(rng.range(1..=300000));
+
+    let total: usize = files.iter().flatten().sum();
+    let target = rng.range(41000000..=69000000);
+    for size in files.iter_mut().flatten() {
+        *size = (*size * target / total).max(1);
+    }
+
+    /// Bijective base 26, so every directory gets a unique name.
+    fn name(mut n: usize) -> String {
+        let mut name = vec![];
+        loop {
+            name.push((b'a' + (n % 26) as u8) as char);
+            if n < 26 {
+                break;
+            }
+            n = n / 26 - 1;
+        }
+        name.iter().rev().collect()
+    }
+
+    fn explore(dir: usize, children: &[Vec<usize>], files: &[Vec<usize>], input: &mut String) {
+        input.push_str("$ ls\n");
+        for &child in &children[dir] {
+            input.push_str(&format!("dir {}\n", name(child)));
+        }
+        for (i, size) in files[dir].iter().enumerate() {
+            input.push_str(&format!("{} {}.{}\n", size, name(dir), i));
+        }
+
+        for &child in &children[dir] {
+            input.push_str(&format!("$ cd {}\n", name(child)));
+            explore(child, children, files, input);
+            input.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut input = "$ cd /\n".to_string();
+    explore(0, &children, &files, &mut input);
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&parsed), 95437);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&parsed), 24933642);
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+use crate::{
+    error::ParseError,
+    generate::Rng,
+    geometry::{Grid, Point},
+    repl::{self, Session},
+    solution::Solution,
+};
+
+pub struct Day8;
+
+pub struct Trees {
+    grid: Grid<usize>,
+}
+
+impl FromStr for Trees {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, |char| {
+            char.to_digit(10)
+                .map(|height| height as usize)
+                .ok_or_else(|| "expected a tree height digit".to_string())
+        })?;
+        if grid.width() == 0 {
+            return Err(ParseError::new("there are no trees"));
+        }
+
+        Ok(Trees { grid })
+    }
+}
+
+impl Trees {
+    fn is_visible(&self, point: Point<usize>) -> bool {
+        self.is_visible_top(point)
+            || self.is_visible_left(point)
+            || self.is_visible_down(point)
+            || self.is_visible_right(point)
+    }
+
+    fn is_visible_top(&self, point: Point<usize>) -> bool {
+        let tree = &self.grid[point];
+
+        (0..point.y)
+            .map(|y| &self.grid[Point::new(point.x, y)])
+            .filter(|height| height >= &tree)
+            .count()
+            == 0
+    }
+
+    fn is_visible_left(&self, point: Point<usize>) -> bool {
+        let tree = &self.grid[point];
+
+        (0..point.x)
+            .map(|x| &self.grid[Point::new(x, point.y)])
+            .filter(|height| height >= &tree)
+            .count()
+            == 0
+    }
+
+    fn is_visible_down(&self, point: Point<usize>) -> bool {
+        let tree = &self.grid[point];
+
+        (point.y..self.grid.height())
+            .skip(1)
+            .map(|y| &self.grid[Point::new(point.x, y)])
+            .filter(|height| height >= &tree)
+            .count()
+            == 0
+    }
+
+    fn is_visible_right(&self, point: Point<usize>) -> bool {
+        let tree = &self.grid[point];
+
+        (point.x..self.grid.width())
+            .skip(1)
+            .map(|x| &self.grid[Point::new(x, point.y)])
+            .filter(|height| height >= &tree)
+            .count()
+            == 0
+    }
+}
+
+impl Trees {
+    fn scenic_score(&self, point: Point<usize>) -> usize {
+        self.distance_top(point)
+            * self.distance_down(point)
+            * self.distance_left(point)
+            * self.distance_right(point)
+    }
+
+    fn distance_top(&self, point: Point<usize>) -> usize {
+        let tree = &self.grid[point];
+
+        let stop_point = (0..point.y).rev().map(|y| Point { x: point.x, y }).fold(
+            point - Point { x: 0, y: 1 },
+            |point, next_point| {
+                let point_height = &self.grid[point];
+
+                if point_height >= tree {
+                    point
+                } else {
+                    next_point
+                }
+            },
+        );
+
+        point.manhattan_distance(&stop_point)
+    }
+
+    fn distance_down(&self, point: Point<usize>) -> usize {
+        let tree = &self.grid[point];
+
+        let stop_point = (point.y..self.grid.height())
+            .skip(2)
+            .map(|y| Point { x: point.x, y })
+            .fold(point + Point { x: 0, y: 1 }, |point, next_point| {
+                let point_height = &self.grid[point];
+
+                if point_height >= tree {
+                    point
+                } else {
+                    next_point
+                }
+            });
+
+        point.manhattan_distance(&stop_point)
+    }
+
+    fn distance_left(&self, point: Point<usize>) -> usize {
+        let tree = &self.grid[point];
+
+        let stop_point = (0..point.x).rev().map(|x| Point { x, y: point.y }).fold(
+            point - Point { x: 1, y: 0 },
+            |point, next_point| {
+                let point_height = &self.grid[point];
+
+                if point_height >= tree {
+                    point
+                } else {
+                    next_point
+                }
+            },
+        );
+
+        point.manhattan_distance(&stop_point)
+    }
+
+    fn distance_right(&self, point: Point<usize>) -> usize {
+        let tree = &self.grid[point];
+
+        let stop_point = (point.x..self.grid.width())
+            .skip(2)
+            .map(|x| Point { x, y: point.y })
+            .fold(point + Point { x: 1, y: 0 }, |point, next_point| {
+                let point_height = &self.grid[point];
+
+                if point_height >= tree {
+                    point
+                } else {
+                    next_point
+                }
+            });
+
+        point.manhattan_distance(&stop_point)
+    }
+}
+
+impl fmt::Debug for Trees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{\n")?;
+        for y in 0..self.grid.height() {
+            f.write_char('\t')?;
+            for x in 0..self.grid.width() {
+                let point = Point { x, y };
+                if self.is_visible(point) {
+                    f.write_str("\x1b[0;31m")?;
+                    f.write_str(&self.grid[Point::new(x, y)].to_string())?;
+                    f.write_str("\x1b[0m")?;
+                } else {
+                    f.write_str(&self.grid[Point::new(x, y)].to_string())?;
+                }
+            }
+            f.write_char('\n')?;
+        }
+        f.write_str("}")?;
+
+        Ok(())
+    }
+}
+
+impl Solution for Day8 {
+    type Parsed = Trees;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse::<Trees>()
+    }
+
+    fn part1(trees: &Self::Parsed) -> Self::Part1 {
+        (0..trees.grid.width())
+            .flat_map(|x| (0..trees.grid.height()).map(move |y| (x, y)))
+            .filter(|(x, y)| trees.is_visible(Point { x: *x, y: *y }))
+            .count()
+    }
+
+    fn part2(trees: &Self::Parsed) -> Self::Part2 {
+        (1..(trees.grid.width() - 1))
+            .flat_map(|x| (1..(trees.grid.height() - 1)).map(move |y| (x, y)))
+            .map(|(x, y)| trees.scenic_score(Point { x, y }))
+            .fold(0, |current, score| current.max(score))
+    }
+}
+
+impl Trees {
+    /// The tree at `x` and `y` of the arguments.
+    fn point(&self, args: &[&str]) -> Result<Point<usize>, String> {
+        let point = Point::new(repl::arg(args, 0, "x")?, repl::arg(args, 1, "y")?);
+        if point.x >= self.grid.width() || point.y >= self.grid.height() {
+            return Err(format!(
+                "{},{} is outside the {} by {} forest",
+                point.x,
+                point.y,
+                self.grid.width(),
+                self.grid.height()
+            ));
+        }
+        Ok(point)
+    }
+
+    fn is_on_edge(&self, point: Point<usize>) -> bool {
+        point.x == 0
+            || point.y == 0
+            || point.x == self.grid.width() - 1
+            || point.y == self.grid.height() - 1
+    }
+}
+
+const COMMANDS: &[repl::Command] = &[
+    repl::Command {
+        name: "scenic",
+        args: "<x> <y>",
+        help: "scenic score of the tree and how far it sees each way",
+    },
+    repl::Command {
+        name: "visible",
+        args: "<x> <y>",
+        help: "sides the tree is visible from",
+    },
+];
+
+impl Session for Trees {
+    fn commands(&self) -> &'static [repl::Command] {
+        COMMANDS
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        let point = self.point(args)?;
+        match command {
+            // The distances can't be measured towards the edge
+            "scenic" if self.is_on_edge(point) => {
+                Ok("scenic score 0, the tree is on the edge".to_string())
+            }
+            "scenic" => Ok(format!(
+                "scenic score {}, seeing {} up, {} left, {} down and {} right",
+                self.scenic_score(point),
+                self.distance_top(point),
+                self.distance_left(point),
+                self.distance_down(point),
+                self.distance_right(point)
+            )),
+            "visible" => {
+                let sides: Vec<&str> = [
+                    ("top", self.is_visible_top(point)),
+                    ("left", self.is_visible_left(point)),
+                    ("bottom", self.is_visible_down(point)),
+                    ("right", self.is_visible_right(point)),
+                ]
+                .into_iter()
+                .filter(|(_, visible)| *visible)
+                .map(|(side, _)| side)
+                .collect();
+
+                if sides.is_empty() {
+                    Ok("hidden from every side".to_string())
+                } else {
+                    Ok(format!("visible from the {}", sides.join(", ")))
+                }
+            }
+            _ => Err(format!("unknown command '{}'", command)),
+        }
+    }
+}
+
+pub fn explore(input: &str, _part: u8) -> Result<Box<dyn Session>, ParseError> {
+    Ok(Box::new(Day8::parse(input)?))
+}
+
+/// Generates a `size` by `size` forest.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::new();
+    for _ in 0..size {
+        input.extend((0..size).map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap()));
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&parsed), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&parsed), 8);
+    }
+    #[test]
+    fn locates_invalid_heights() {
+        let error = Day8::parse("303\n2x5\n").unwrap_err();
+        assert_eq!(error.message, "expected a tree height digit");
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+
+use crate::{
+    animate::Simulation,
+    error::{parse_lines, parse_number, ParseError},
+    generate::Rng,
+    geometry::{Direction, Point},
+    solution::Solution,
+};
+
+pub struct Day9;
+
+#[derive(Hash, Clone, Debug)]
+struct Rope {
+    knots: Vec<Point<i32>>,
+}
+
+impl Rope {
+    fn move_head(&mut self, direction: Direction) {
+        let head = self.knots.get_mut(0).unwrap();
+        *head = head.step(direction).unwrap();
+
+        self.update_tail();
+    }
+
+    fn update_tail(&mut self) {
+        for i in 0..(self.knots.len() - 1) {
+            let head = *self.knots.get(i).unwrap();
+            let tail = self.knots.get_mut(i + 1).unwrap();
+
+            if head.chebyshev_distance(tail) > 1 {
+                let delta = head - *tail;
+                *tail += Point::new(delta.x.signum(), delta.y.signum());
+            }
+        }
+    }
+
+    fn get_tail(&self) -> &Point<i32> {
+        self.knots.last().unwrap()
+    }
+
+    fn new(n_knots: i32) -> Rope {
+        Rope {
+            knots: vec![Point::default(); n_knots.try_into().unwrap()],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Move {
+    direction: Direction,
+    amount: u32,
+}
+
+fn get_move(s: &str) -> Result<Move, ParseError> {
+    let (direction, amount_str) = s
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at(s, s.len(), "expected a direction and an amount"))?;
+
+    let amount: u32 = parse_number(s, amount_str)?;
+
+    let direction = match direction {
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        _ => return Err(ParseError::span(s, direction, "unknown direction")),
+    };
+
+    Ok(Move { direction, amount })
+}
+
+/// Moves the head of a rope with `n_knots` knots and counts the unique
+/// positions visited by its tail.
+fn count_tail_positions(moves: &[Move], n_knots: i32) -> usize {
+    let mut rope = Rope::new(n_knots);
+
+    let mut unique_coordinates: HashSet<Point<i32>> = HashSet::new();
+    unique_coordinates.insert(*rope.get_tail());
+
+    for m in moves {
+        for _ in 0..m.amount {
+            rope.move_head(m.direction);
+            unique_coordinates.insert(*rope.get_tail());
+        }
+    }
+
+    unique_coordinates.len()
+}
+
+/// The rope following its head one step at a time.
+struct Walk {
+    rope: Rope,
+    moves: Vec<Move>,
+    next: usize,
+    /// Steps already taken of the next move.
+    taken: u32,
+    visited: HashSet<Point<i32>>,
+}
+
+impl Walk {
+    /// Size of the window around the head that's drawn.
+    const VIEW: (i32, i32) = (79, 21);
+
+    fn new(moves: Vec<Move>, n_knots: i32) -> Walk {
+        let rope = Rope::new(n_knots);
+        let visited = HashSet::from([*rope.get_tail()]);
+
+        Walk {
+            rope,
+            moves,
+            next: 0,
+            taken: 0,
+            visited,
+        }
+    }
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> bool {
+        while let Some(m) = self.moves.get(self.next) {
+            if self.taken < m.amount {
+                self.rope.move_head(m.direction);
+                self.visited.insert(*self.rope.get_tail());
+                self.taken += 1;
+                return true;
+            }
+
+            self.next += 1;
+            self.taken = 0;
+        }
+
+        false
+    }
+
+    fn frame(&self) -> String {
+        let head = self.rope.knots[0];
+        let (width, height) = Walk::VIEW;
+        let top_left = head - Point::new(width / 2, height / 2);
+        let n_knots = self.rope.knots.len();
+
+        let mut frame = String::new();
+        for y in top_left.y..top_left.y + height {
+            for x in top_left.x..top_left.x + width {
+                let point = Point::new(x, y);
+                let knot = self.rope.knots.iter().position(|&knot| knot == point);
+                frame.push(match knot {
+                    Some(0) => 'H',
+                    Some(_) if n_knots == 2 => 'T',
+                    Some(i) => char::from_digit(i as u32, 10).unwrap_or('*'),
+                    None if point == Point::default() => 's',
+                    None if self.visited.contains(&point) => '#',
+                    None => '.',
+                });
+            }
+            frame.push('\n');
+        }
+
+        frame
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "move {} of {}, the tail visited {} positions",
+            (self.next + 1).min(self.moves.len()),
+            self.moves.len(),
+            self.visited.len()
+        )
+    }
+}
+
+impl Solution for Day9 {
+    type Parsed = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, get_move)
+    }
+
+    fn part1(moves: &Self::Parsed) -> Self::Part1 {
+        count_tail_positions(moves, 2)
+    }
+
+    fn part2(moves: &Self::Parsed) -> Self::Part2 {
+        count_tail_positions(moves, 10)
+    }
+}
+
+/// Moves the rope of the part, 2 knots for part 1 and 10 for part 2, with
+/// the view following its head.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let n_knots = if part == 1 { 2 } else { 10 };
+
+    Ok(Box::new(Walk::new(Day9::parse(input)?, n_knots)))
+}
+
+/// Generates `size` moves of the head, at least the one the parser needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let direction = rng.choose(&['L', 'R', 'U', 'D']);
+        input.push_str(&format!("{} {}\n", direction, rng.range(1..=20)));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&parsed), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let parsed = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&parsed), 1);
+    }
+
+    #[test]
+    fn part2_larger_example() {
+        let parsed = Day9::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day9::part2(&parsed), 36);
+    }
+}
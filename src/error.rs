@@ -0,0 +1,254 @@
+use std::{fmt, io, net::SocketAddr, path::PathBuf, str::FromStr};
+
+#[derive(Debug)]
+pub enum Error {
+    MissingInput {
+        path: PathBuf,
+    },
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    Parse {
+        day: u8,
+        source: ParseError,
+    },
+    InvalidFile {
+        path: PathBuf,
+        message: String,
+    },
+    /// No session token to download inputs with, `path` being the file it
+    /// can be stored in.
+    MissingSession {
+        path: Option<PathBuf>,
+    },
+    Fetch {
+        day: u8,
+        message: String,
+    },
+    DayExists {
+        day: u8,
+        path: PathBuf,
+    },
+    /// A solution panicked, caught so the other days still run.
+    Panicked {
+        message: String,
+    },
+    Serve {
+        addr: SocketAddr,
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { path } => {
+                write!(f, "input file not found: {}", path.display())
+            }
+            Error::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "couldn't read {}: {}", path.display(), source),
+            Error::Io { path: None, source } => write!(f, "couldn't read stdin: {}", source),
+            Error::Parse { day, source } => {
+                write!(f, "couldn't parse the day {} input, {}", day, source)
+            }
+            Error::InvalidFile { path, message } => {
+                write!(f, "invalid file {}: {}", path.display(), message)
+            }
+            Error::MissingSession { path: Some(path) } => write!(
+                f,
+                "no session token, set AOC_SESSION or write it to {}",
+                path.display()
+            ),
+            Error::MissingSession { path: None } => {
+                write!(f, "no session token, set AOC_SESSION")
+            }
+            Error::Fetch { day, message } => {
+                write!(f, "couldn't fetch the day {} input: {}", day, message)
+            }
+            Error::DayExists { day, path } => {
+                write!(f, "day {} already exists at {}", day, path.display())
+            }
+            Error::Panicked { message } => write!(f, "panicked: {}", message),
+            Error::Serve { addr, message } => {
+                write!(f, "couldn't listen on {}: {}", addr, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Where in the input a [`ParseError`] was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending line.
+    pub text: String,
+}
+
+/// A malformed puzzle input.
+///
+/// Parsers of single lines report errors as if the line was the whole input,
+/// the caller then moves them to the right line with [`ParseError::offset`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl ParseError {
+    /// An error with the input as a whole, like a missing start position.
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// An error at the 0-based character `column` of `text`.
+    pub fn at(text: &str, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            location: Some(Location {
+                line: 1,
+                column: column + 1,
+                text: text.to_string(),
+            }),
+        }
+    }
+
+    /// An error at `part`, which has to be a slice of `text`.
+    pub fn span(text: &str, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(text, column_of(text, part), message)
+    }
+
+    /// Moves the error `lines` lines down, for when the text it was found in
+    /// doesn't start at the beginning of the input.
+    pub fn offset(mut self, lines: usize) -> ParseError {
+        if let Some(location) = &mut self.location {
+            location.line += lines;
+        }
+        self
+    }
+
+    /// Moves an error found in `part` to the same position in `text`, the
+    /// line `part` was sliced from.
+    pub fn within(mut self, text: &str, part: &str) -> ParseError {
+        if let Some(location) = &mut self.location {
+            location.column += column_of(text, part);
+            location.text = text.to_string();
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(location) = &self.location else {
+            return write!(f, "{}", self.message);
+        };
+
+        let gutter = location.line.to_string().len();
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            location.line, location.column, self.message
+        )?;
+        writeln!(f, "{} | {}", location.line, location.text)?;
+        write!(
+            f,
+            "{:gutter$} | {:>column$}",
+            "",
+            "^",
+            column = location.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses the number at `part`, which has to be a slice of `text`.
+pub fn parse_number<T>(text: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.parse::<T>()
+        .map_err(|e| ParseError::span(text, part, format!("invalid number '{}': {}", part, e)))
+}
+
+/// Parses every line with `parse`, moving its errors to the line they were
+/// found on.
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.offset(i)))
+        .collect()
+}
+
+/// The character column at which `part` starts in `text`.
+fn column_of(text: &str, part: &str) -> usize {
+    let start = (part.as_ptr() as usize).saturating_sub(text.as_ptr() as usize);
+
+    text.get(..start.min(text.len()))
+        .map(|before| before.chars().count())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_spans() {
+        let line = "move x from 1 to 2";
+        let error = parse_number::<usize>(line, &line[5..6])
+            .unwrap_err()
+            .offset(2);
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (3, 6));
+        assert_eq!(location.text, line);
+    }
+
+    #[test]
+    fn moves_errors_into_enclosing_line() {
+        let line = "498,4 -> 498,x";
+        let point = &line[9..];
+        let error = parse_number::<i32>(point, &point[4..])
+            .unwrap_err()
+            .within(line, point);
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (1, 14));
+        assert_eq!(location.text, line);
+    }
+
+    #[test]
+    fn displays_a_caret_under_the_column() {
+        let error = ParseError::at("noop 3", 5, "unexpected argument").offset(9);
+
+        assert_eq!(
+            error.to_string(),
+            "line 10, column 6: unexpected argument\n10 | noop 3\n   |      ^"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+#[cfg(feature = "fetch")]
+use std::{
+    fs, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "fetch")]
+use crate::error::Error;
+
+#[cfg(feature = "fetch")]
+pub const DEFAULT_URL: &str = "https://adventofcode.com/2022";
+
+/// Least time between two requests to the server, across runs too.
+#[cfg(feature = "fetch")]
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[cfg(feature = "fetch")]
+const USER_AGENT: &str = concat!("adventofcode2022/", env!("CARGO_PKG_VERSION"));
+
+/// Downloads puzzle inputs into a cache, one `dayN.txt` per day.
+#[cfg(feature = "fetch")]
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    /// The event's URL, inputs are at `{url}/day/{day}/input`.
+    pub url: String,
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: String,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+#[cfg(feature = "fetch")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was already cached and left as it was.
+    Cached(PathBuf),
+}
+
+/// Where inputs are cached, `AOC_CACHE_DIR` or the user's data directory,
+/// falling back to `target/inputs` for users without one.
+pub fn cache_dir() -> PathBuf {
+    match env::var_os("AOC_CACHE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => dirs::data_dir()
+            .map(|dir| dir.join("adventofcode2022"))
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("target"))
+            .join("inputs"),
+    }
+}
+
+/// The file the session token is read from when `AOC_SESSION` isn't set.
+pub fn session_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("adventofcode2022").join("session"))
+}
+
+/// The cached input of the day, if it was fetched.
+pub fn cached_input(day: u8) -> Option<PathBuf> {
+    Some(cache_dir().join(format!("day{}.txt", day))).filter(|path| path.exists())
+}
+
+#[cfg(feature = "fetch")]
+impl Fetcher {
+    /// A fetcher configured by the environment: the session token comes from
+    /// `AOC_SESSION` or the [`session_path`] file, and `AOC_URL` replaces
+    /// the server.
+    pub fn from_env() -> Result<Fetcher, Error> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => session_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .unwrap_or_default(),
+        };
+        let session = session.trim().to_string();
+        if session.is_empty() {
+            return Err(Error::MissingSession {
+                path: session_path(),
+            });
+        }
+
+        Ok(Fetcher {
+            url: env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_string()),
+            session,
+            cache_dir: cache_dir(),
+            min_interval: MIN_INTERVAL,
+        })
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}.txt", day))
+    }
+
+    /// Downloads the input of the day unless it's cached already, in which
+    /// case it's only downloaded again when `force` is set.
+    pub fn fetch(&self, day: u8, force: bool) -> Result<Fetched, Error> {
+        let path = self.cached_path(day);
+        if path.exists() && !force {
+            return Ok(Fetched::Cached(path));
+        }
+
+        fs::create_dir_all(&self.cache_dir).map_err(|source| Error::Io {
+            path: Some(self.cache_dir.clone()),
+            source,
+        })?;
+        self.wait_for_turn()?;
+        let input = self.download(day)?;
+
+        // Written next to the cache first, so a failed write doesn't leave a
+        // truncated input behind
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|source| Error::Io {
+                path: Some(path.clone()),
+                source,
+            })?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn download(&self, day: u8) -> Result<String, Error> {
+        let url = format!("{}/day/{}/input", self.url.trim_end_matches('/'), day);
+        let fetch_error = |message: String| Error::Fetch { day, message };
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| fetch_error(e.to_string())),
+            Err(ureq::Error::Status(status @ (400 | 401 | 403), _)) => Err(fetch_error(format!(
+                "the server answered {}, the session token is probably wrong or expired",
+                status
+            ))),
+            Err(ureq::Error::Status(404, _)) => Err(fetch_error(
+                "the server answered 404, the puzzle isn't unlocked yet".to_string(),
+            )),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(fetch_error(format!("the server answered {}", status)))
+            }
+            Err(ureq::Error::Transport(transport)) => Err(fetch_error(transport.to_string())),
+        }
+    }
+
+    /// Sleeps until `min_interval` passed since the last request, which is
+    /// recorded in the cache so separate runs wait for each other too.
+    fn wait_for_turn(&self) -> Result<(), Error> {
+        let stamp = self.cache_dir.join(".last-request");
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+
+        if let Some(last) = read_stamp(&stamp) {
+            if let Some(wait) = (last + self.min_interval).checked_sub(now()) {
+                thread::sleep(wait.min(self.min_interval));
+            }
+        }
+
+        fs::write(&stamp, now().as_millis().to_string()).map_err(|source| Error::Io {
+            path: Some(stamp),
+            source,
+        })
+    }
+}
+
+#[cfg(feature = "fetch")]
+fn read_stamp(path: &Path) -> Option<Duration> {
+    let millis = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    Some(Duration::from_millis(millis))
+}
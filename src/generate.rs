@@ -0,0 +1,131 @@
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "solutions")]
+use crate::*;
+
+/// SplitMix64, small and stable so a seed generates the same input on every
+/// platform and version.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` needs to be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below(range.end() - range.start() + 1)
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+type Generator = fn(&mut Rng, usize) -> String;
+
+const GENERATORS: &[(u8, Generator)] = &[
+    #[cfg(feature = "day1")]
+    (1, day1::generate),
+    #[cfg(feature = "day2")]
+    (2, day2::generate),
+    #[cfg(feature = "day3")]
+    (3, day3::generate),
+    #[cfg(feature = "day4")]
+    (4, day4::generate),
+    #[cfg(feature = "day5")]
+    (5, day5::generate),
+    #[cfg(feature = "day6")]
+    (6, day6::generate),
+    #[cfg(feature = "day7")]
+    (7, day7::generate),
+    #[cfg(feature = "day8")]
+    (8, day8::generate),
+    #[cfg(feature = "day9")]
+    (9, day9::generate),
+    #[cfg(feature = "day10")]
+    (10, day10::generate),
+    #[cfg(feature = "day11")]
+    (11, day11::generate),
+    #[cfg(feature = "day12")]
+    (12, day12::generate),
+    #[cfg(feature = "day13")]
+    (13, day13::generate),
+    #[cfg(feature = "day14")]
+    (14, day14::generate),
+];
+
+/// Generates a valid input for the day, `size` roughly being the number of
+/// lines or records. The same seed always generates the same input.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    GENERATORS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, generator)| generator(&mut Rng::new(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{reference, runner};
+
+    #[test]
+    fn generates_deterministically() {
+        for (day, _) in GENERATORS {
+            assert_eq!(generate(*day, 20, 7), generate(*day, 20, 7), "day {}", day);
+            assert_ne!(generate(*day, 20, 1), generate(*day, 20, 2), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        for seed in 0..3 {
+            for size in [0, 1, 10, 40] {
+                for puzzle in runner::PUZZLES {
+                    // Days just scaffolded don't have a generator yet
+                    let Some(input) = generate(puzzle.day, size, seed) else {
+                        continue;
+                    };
+                    // Some generated inputs are edge cases without an answer,
+                    // which the reference turns down as well
+                    let invalid = reference::days().any(|day| day == puzzle.day)
+                        && reference::solve(puzzle.day, puzzle.part, &input).is_none();
+                    if invalid {
+                        continue;
+                    }
+                    if let Err(e) = puzzle.run(&input) {
+                        panic!(
+                            "day {} part {} size {} seed {}: {}",
+                            puzzle.day, puzzle.part, size, seed, e
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
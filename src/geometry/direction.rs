@@ -0,0 +1,122 @@
+/// The four orthogonal directions. The y axis points down, like rows in a
+/// text map.
+#[derive(Hash, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// The four orthogonal and four diagonal directions.
+#[derive(Hash, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opposites_cancel_out() {
+        for direction in Direction::ALL {
+            let (dx, dy) = direction.delta();
+            let (ox, oy) = direction.opposite().delta();
+
+            assert_eq!((dx + ox, dy + oy), (0, 0));
+            assert_eq!(direction.opposite().opposite(), direction);
+        }
+    }
+
+    #[test]
+    fn orthogonal_directions_keep_their_delta() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction8::from(direction).delta(), direction.delta());
+        }
+        assert_eq!(Direction::Up.delta(), (0, -1));
+    }
+
+    #[test]
+    fn eight_directions_surround_the_origin() {
+        let mut deltas: Vec<(isize, isize)> = Direction8::ALL.iter().map(|d| d.delta()).collect();
+        deltas.sort();
+        deltas.dedup();
+
+        assert_eq!(deltas.len(), 8);
+        assert!(deltas
+            .iter()
+            .all(|&(dx, dy)| dx.abs() <= 1 && dy.abs() <= 1));
+    }
+}
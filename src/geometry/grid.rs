@@ -0,0 +1,238 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use super::Point;
+use crate::error::ParseError;
+
+/// A dense grid stored row by row.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line, converting each character
+    /// with `cell`. All rows need to be of the same width.
+    pub fn parse<F>(s: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in s.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let row_width = line.chars().count();
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let message = format!("row is {} wide, expected {}", row_width, width);
+                    return Err(ParseError::at(line, row_width.min(width), message).offset(y));
+                }
+                _ => {}
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).map_err(|message| ParseError::at(line, x, message).offset(y))?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    fn index_of(&self, point: Point<usize>) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y * self.width + point.x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The orthogonally adjacent points that are inside the grid.
+    pub fn neighbours(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point.neighbours().filter(|p| self.contains(*p))
+    }
+
+    /// The orthogonally and diagonally adjacent points that are inside the
+    /// grid.
+    pub fn neighbours8(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point.neighbours8().filter(|p| self.contains(*p))
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, Ok)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("rows", &self.rows().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rows() {
+        let grid: Grid<char> = "ab\r\ncd\n".parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+    }
+
+    #[test]
+    fn parses_empty_input() {
+        let grid: Grid<char> = "".parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.get(Point::new(0, 0)), None);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = "abc\nab\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(error.message, "row is 2 wide, expected 3");
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 3));
+
+        let error = "ab\nabc\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(error.location.unwrap().column, 3);
+    }
+
+    #[test]
+    fn locates_cells_that_fail_to_convert() {
+        let error = Grid::parse("12\n3x\n", |c| {
+            c.to_digit(10).ok_or("expected a digit".to_string())
+        })
+        .unwrap_err();
+
+        let location = error.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+    }
+
+    #[test]
+    fn keeps_neighbours_inside_the_grid() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+
+        assert_eq!(grid.neighbours(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours(Point::new(2, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.position(|&c| c == 'f'), Some(Point::new(2, 1)));
+    }
+}
@@ -0,0 +1,9 @@
+mod direction;
+mod grid;
+mod point;
+mod rect;
+
+pub use direction::{Direction, Direction8};
+pub use grid::Grid;
+pub use point::{Point, Scalar};
+pub use rect::Rect;
@@ -0,0 +1,212 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::{Direction, Direction8};
+use crate::error::{parse_number, ParseError};
+
+/// Numeric types that can be used as point coordinates.
+pub trait Scalar:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + TryFrom<i128> + TryInto<i128>
+{
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// Adds a signed delta, returning `None` if the result doesn't fit in
+    /// `Self`. Goes through `i128`, which holds every value of the scalars.
+    fn offset(self, delta: isize) -> Option<Self> {
+        let value: i128 = self.try_into().ok()?;
+        Self::try_from(value + delta as i128).ok()
+    }
+}
+
+impl Scalar for i32 {}
+impl Scalar for i64 {}
+impl Scalar for isize {}
+impl Scalar for u32 {}
+impl Scalar for u64 {}
+impl Scalar for usize {}
+
+#[derive(Hash, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T: Scalar> Point<T> {
+    pub fn manhattan_distance(&self, other: &Point<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Point<T>) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Moves the point by a signed delta, returning `None` if it would end up
+    /// outside of what `T` can represent, like left of zero for `usize`.
+    pub fn translate(&self, dx: isize, dy: isize) -> Option<Point<T>> {
+        Some(Point {
+            x: self.x.offset(dx)?,
+            y: self.y.offset(dy)?,
+        })
+    }
+
+    pub fn step(&self, direction: Direction) -> Option<Point<T>> {
+        let (dx, dy) = direction.delta();
+        self.translate(dx, dy)
+    }
+
+    pub fn step8(&self, direction: Direction8) -> Option<Point<T>> {
+        let (dx, dy) = direction.delta();
+        self.translate(dx, dy)
+    }
+
+    /// The up to four orthogonally adjacent points.
+    pub fn neighbours(self) -> impl Iterator<Item = Point<T>> {
+        Direction::ALL.into_iter().filter_map(move |d| self.step(d))
+    }
+
+    /// The up to eight orthogonally and diagonally adjacent points.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point<T>> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step8(d))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from(p: (T, T)) -> Self {
+        Point { x: p.0, y: p.1 }
+    }
+}
+
+/// Parses points written as `x,y`.
+impl<T: FromStr> FromStr for Point<T>
+where
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::span(s, s.trim_start(), "expected a point like 'x,y'"))?;
+
+        Ok(Point {
+            x: parse_number(s, x.trim())?,
+            y: parse_number(s, y.trim())?,
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translates_within_the_scalar() {
+        let origin: Point<usize> = Point::new(0, 0);
+
+        assert_eq!(origin.translate(2, 3), Some(Point::new(2, 3)));
+        assert_eq!(origin.translate(-1, 0), None);
+        assert_eq!(origin.translate(0, -1), None);
+        assert_eq!(Point::new(usize::MAX, 0).translate(1, 0), None);
+        assert_eq!(
+            Point::new(-1i32, 0).translate(-1, 0),
+            Some(Point::new(-2, 0))
+        );
+        assert_eq!(usize::MAX.offset(-1), Some(usize::MAX - 1));
+    }
+
+    #[test]
+    fn leaves_out_neighbours_below_zero() {
+        let corner: Vec<Point<usize>> = Point::new(0, 0).neighbours().collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+
+        let corner: Vec<Point<usize>> = Point::new(0, 0).neighbours8().collect();
+        assert_eq!(
+            corner,
+            vec![Point::new(1, 0), Point::new(1, 1), Point::new(0, 1)]
+        );
+
+        assert_eq!(Point::new(0usize, 5).neighbours().count(), 3);
+        assert_eq!(Point::new(0usize, 5).neighbours8().count(), 5);
+        assert_eq!(Point::new(5i32, 5).neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point::new(1i32, -2);
+        let b = Point::new(-3, 4);
+
+        assert_eq!(a.manhattan_distance(&b), 10);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+        assert_eq!(a.manhattan_distance(&a), 0);
+        assert_eq!(
+            Point::new(5usize, 0).manhattan_distance(&Point::new(2, 3)),
+            6
+        );
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!("3, -4".parse::<Point<i32>>().unwrap(), Point::new(3, -4));
+        assert!("3".parse::<Point<i32>>().is_err());
+        assert!("-3,4".parse::<Point<usize>>().is_err());
+    }
+}
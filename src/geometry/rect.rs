@@ -0,0 +1,79 @@
+use super::{Point, Scalar};
+
+/// An axis aligned rectangle where all four edges are inclusive.
+#[derive(Hash, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rect<T> {
+    pub left: T,
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+}
+
+impl<T: Scalar> Rect<T> {
+    /// A rectangle covering only `point`.
+    pub fn new(point: Point<T>) -> Rect<T> {
+        Rect {
+            left: point.x,
+            top: point.y,
+            right: point.x,
+            bottom: point.y,
+        }
+    }
+
+    /// The smallest rectangle containing all `points`, or `None` if there are
+    /// no points.
+    pub fn bounding<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Rect<T>> {
+        let mut points = points.into_iter();
+        let mut rect = Rect::new(points.next()?);
+        points.for_each(|p| rect.encapsulate(&p));
+
+        Some(rect)
+    }
+
+    /// Grows the rectangle so that it contains `point`.
+    pub fn encapsulate(&mut self, point: &Point<T>) {
+        self.left = self.left.min(point.x);
+        self.right = self.right.max(point.x);
+        self.top = self.top.min(point.y);
+        self.bottom = self.bottom.max(point.y);
+    }
+
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        (self.left..=self.right).contains(&point.x) && (self.top..=self.bottom).contains(&point.y)
+    }
+
+    pub fn top_left(&self) -> Point<T> {
+        Point::new(self.left, self.top)
+    }
+
+    pub fn bottom_right(&self) -> Point<T> {
+        Point::new(self.right, self.bottom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_points() {
+        let points = [Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)];
+        let rect = Rect::bounding(points).unwrap();
+
+        assert_eq!(
+            (rect.top_left(), rect.bottom_right()),
+            (Point::new(-3, -1), Point::new(2, 4))
+        );
+        assert!(points.iter().all(|p| rect.contains(p)));
+        assert!(!rect.contains(&Point::new(3, 0)));
+    }
+
+    #[test]
+    fn bounds_a_single_point_or_none() {
+        let rect = Rect::bounding([Point::new(1usize, 2)]).unwrap();
+
+        assert_eq!(rect, Rect::new(Point::new(1, 2)));
+        assert!(rect.contains(&Point::new(1, 2)));
+        assert_eq!(Rect::<usize>::bounding([]), None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{error::Error, fetch};
+
+/// Where a puzzle input is loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The bundled `input.txt` of the day.
+    Puzzle,
+    /// The bundled example of the day, `example{part}.txt` if it exists and
+    /// `example.txt` otherwise.
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interprets a command line path, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("day{}", day))
+}
+
+/// The bundled `input.txt` of the day, or the one `aoc fetch` downloaded if
+/// there's none.
+pub fn puzzle_path(day: u8) -> PathBuf {
+    let bundled = day_dir(day).join("input.txt");
+    if bundled.exists() {
+        return bundled;
+    }
+
+    fetch::cached_input(day).unwrap_or(bundled)
+}
+
+pub fn example_path(day: u8, part: u8) -> PathBuf {
+    let part_example = day_dir(day).join(format!("example{}.txt", part));
+    if part_example.exists() {
+        part_example
+    } else {
+        day_dir(day).join("example.txt")
+    }
+}
+
+/// The file the input is read from, `None` for stdin.
+pub fn path(day: u8, part: u8, source: &Source) -> Option<PathBuf> {
+    match source {
+        Source::Puzzle => Some(puzzle_path(day)),
+        Source::Example => Some(example_path(day, part)),
+        Source::File(path) => Some(path.clone()),
+        Source::Stdin => None,
+    }
+}
+
+pub fn read(day: u8, part: u8, source: &Source) -> Result<String, Error> {
+    match path(day, part, source) {
+        Some(path) => read_file(&path),
+        None => read_stdin(),
+    }
+}
+
+/// The input as a reader, for inputs too large to read at once. Unlike
+/// [`read`], stdin isn't cached and can only be opened once.
+pub fn open(day: u8, part: u8, source: &Source) -> Result<Box<dyn BufRead>, Error> {
+    let Some(path) = path(day, part, source) else {
+        return Ok(Box::new(io::stdin().lock()));
+    };
+
+    let file = File::open(&path).map_err(|source| file_error(&path, source))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| file_error(path, source))
+}
+
+fn file_error(path: &Path, source: io::Error) -> Error {
+    match source.kind() {
+        io::ErrorKind::NotFound => Error::MissingInput {
+            path: path.to_path_buf(),
+        },
+        _ => Error::Io {
+            path: Some(path.to_path_buf()),
+            source,
+        },
+    }
+}
+
+/// Stdin can only be consumed once, so it's cached for when several parts
+/// are run against it. Only the first caller reads it, the others wait for
+/// its input, or its error as `io::Error` can't be cloned.
+fn read_stdin() -> Result<String, Error> {
+    static STDIN: OnceLock<Result<String, (io::ErrorKind, String)>> = OnceLock::new();
+
+    let input = STDIN.get_or_init(|| {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|e| (e.kind(), e.to_string()))
+    });
+
+    input.clone().map_err(|(kind, message)| Error::Io {
+        path: None,
+        source: io::Error::new(kind, message),
+    })
+}
@@ -0,0 +1,50 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod color;
+pub mod crosscheck;
+pub mod error;
+pub mod fetch;
+pub mod generate;
+pub mod geometry;
+pub mod input;
+pub mod memory;
+pub mod reference;
+#[cfg(feature = "viz")]
+pub mod render;
+pub mod repl;
+pub mod runner;
+pub mod scaffold;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod solution;
+pub mod watch;
+
+#[cfg(feature = "day1")]
+pub mod day1;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+#[cfg(feature = "day13")]
+pub mod day13;
+#[cfg(feature = "day14")]
+pub mod day14;
+#[cfg(feature = "day2")]
+pub mod day2;
+#[cfg(feature = "day3")]
+pub mod day3;
+#[cfg(feature = "day4")]
+pub mod day4;
+#[cfg(feature = "day5")]
+pub mod day5;
+#[cfg(feature = "day6")]
+pub mod day6;
+#[cfg(feature = "day7")]
+pub mod day7;
+#[cfg(feature = "day8")]
+pub mod day8;
+#[cfg(feature = "day9")]
+pub mod day9;
@@ -0,0 +1,886 @@
+use std::process::ExitCode;
+
+use std::{
+    panic,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+#[cfg(feature = "fetch")]
+use adventofcode2022::fetch::{Fetched, Fetcher};
+#[cfg(feature = "repl")]
+use adventofcode2022::repl;
+#[cfg(feature = "viz")]
+use adventofcode2022::{
+    animate::{self, Animator},
+    render::{self, Export, Palette},
+};
+use adventofcode2022::{
+    answers::{self, Answers, Verdict},
+    bench::{self, Baseline},
+    color::Colorize,
+    crosscheck, generate,
+    input::{self, Source},
+    memory::CountingAllocator,
+    reference,
+    runner::{self, Puzzle, Report},
+    scaffold, watch,
+};
+#[cfg(feature = "day1")]
+use adventofcode2022::{
+    day1::{self, Elf, Elves, Ranked, Stats},
+    error::Error,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+#[cfg(feature = "serve")]
+use {
+    adventofcode2022::serve,
+    std::net::{IpAddr, SocketAddr},
+};
+
+/// Counts the heap usage reported by `run --mem`.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, a single part of a day, or every day
+    Run {
+        /// Day to run
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Part to run, both parts are run when omitted
+        part: Option<u8>,
+        /// Run every day
+        #[arg(long)]
+        all: bool,
+        /// Read the puzzle input from a file, or from stdin when `-`
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["example", "all"])]
+        input: Option<String>,
+        /// Use the bundled example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// Number of parts solved at the same time
+        #[arg(long, short, default_value_t = 1)]
+        jobs: usize,
+        /// Report the peak heap usage of parsing and solving each part
+        #[arg(long, conflicts_with = "jobs")]
+        mem: bool,
+        /// Rank the N elves carrying the most calories instead, for day 1
+        #[cfg(feature = "day1")]
+        #[arg(long, value_name = "N", conflicts_with_all = ["all", "part", "jobs", "mem"])]
+        top: Option<usize>,
+        /// Print statistics of the calories the elves carry instead, for day 1
+        #[cfg(feature = "day1")]
+        #[arg(long, conflicts_with_all = ["all", "part", "jobs", "mem", "top"])]
+        stats: bool,
+    },
+    /// Check answers against the ones recorded in the answers file
+    Verify {
+        /// Day to verify, every day is verified when omitted
+        day: Option<u8>,
+        /// Part to verify, both parts are verified when omitted
+        #[arg(requires = "day")]
+        part: Option<u8>,
+        /// Verify against the bundled example inputs
+        #[arg(long)]
+        example: bool,
+        /// Record the answers as known-good, replacing wrong ones
+        #[arg(long)]
+        record: bool,
+        /// Answers file to use instead of the bundled `answers.toml`
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Time each part over several runs and compare against a saved baseline
+    Bench {
+        /// Day to benchmark, every day is benchmarked when omitted
+        day: Option<u8>,
+        /// Part to benchmark, both parts are benchmarked when omitted
+        #[arg(requires = "day")]
+        part: Option<u8>,
+        /// Number of runs per part
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Benchmark the bundled example inputs
+        #[arg(long)]
+        example: bool,
+        /// Baseline file, defaults to `target/bench-baseline.toml`
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// Save the results as the new baseline, done anyway when there's none
+        #[arg(long)]
+        save: bool,
+        /// Percentage of change against the baseline that gets highlighted
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download the puzzle input of a day into the input cache
+    ///
+    /// The session token is read from AOC_SESSION or the session file in the
+    /// user's config directory. Inputs fetched are used by the other commands
+    /// for days without a bundled input.
+    #[cfg(feature = "fetch")]
+    Fetch {
+        /// Day to download the input of
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Download the input again even if it's cached
+        #[arg(long)]
+        force: bool,
+    },
+    /// Answer puzzles over HTTP
+    ///
+    /// `POST /day/{day}/part/{part}` with the puzzle input as the body
+    /// answers with the same JSON as `run --format json`.
+    #[cfg(feature = "serve")]
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8022)]
+        port: u16,
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: IpAddr,
+        /// Largest input accepted, in bytes
+        #[arg(long, default_value_t = 1 << 20)]
+        max_body: usize,
+        /// Seconds a part may take before its request fails
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+        /// Number of requests handled at the same time
+        #[arg(long, default_value_t = 4)]
+        workers: usize,
+        /// Most parts solved at the same time, including ones that timed out
+        #[arg(long, default_value_t = 4)]
+        max_solves: usize,
+    },
+    /// Create the module of a new day and register it in the runner
+    New {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Print a synthetic input for a day
+    Gen {
+        /// Day to generate an input for
+        day: u8,
+        /// Rough number of lines or records to generate
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the generator, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Compare the solutions with naive references on generated inputs
+    ///
+    /// The first input a solution gets wrong is shrunk to a minimal one that
+    /// still reproduces the mismatch.
+    Crosscheck {
+        /// Day to check, every day is checked when omitted
+        day: Option<u8>,
+        /// Part to check, both parts are checked when omitted
+        #[arg(requires = "day")]
+        part: Option<u8>,
+        /// Number of inputs generated per part
+        #[arg(long, default_value_t = 100)]
+        seeds: u64,
+        /// Largest size of the generated inputs, smaller ones shrink faster
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+    },
+    /// Solve a day again whenever its inputs or source change
+    Watch {
+        /// Day to watch
+        day: u8,
+        /// How often to check for changes, in milliseconds
+        #[arg(long, default_value_t = 250)]
+        interval: u64,
+    },
+    /// Play the simulation of a day in the terminal
+    #[cfg(feature = "viz")]
+    Animate {
+        /// Day to animate
+        day: u8,
+        /// Part to animate, for days where the simulations differ
+        #[arg(default_value_t = 1)]
+        part: u8,
+        /// Read the puzzle input from a file, or from stdin when `-`
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+        /// Use the bundled example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Frames drawn per second
+        #[arg(long, default_value_t = 30)]
+        fps: u32,
+        /// Simulation steps per frame, changed with + and - while playing
+        #[arg(long, default_value_t = 1)]
+        speed: usize,
+    },
+    /// Query the parsed input of a day with commands, `help` lists them
+    #[cfg(feature = "repl")]
+    Repl {
+        /// Day to explore
+        day: u8,
+        /// Part whose rules apply, for days where they differ
+        #[arg(default_value_t = 1)]
+        part: u8,
+        /// Read the puzzle input from a file
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+        /// Use the bundled example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+    },
+    /// Render the simulation of a day to an animated GIF or image files
+    #[cfg(feature = "viz")]
+    Export {
+        /// Day to export
+        day: u8,
+        /// Part to export, for days where the simulations differ
+        #[arg(default_value_t = 1)]
+        part: u8,
+        /// GIF file to write, or directory to write the numbered images into
+        #[arg(long, short, value_name = "PATH")]
+        out: PathBuf,
+        /// What to write
+        #[arg(long, value_enum, default_value_t = ImageFormat::Gif)]
+        format: ImageFormat,
+        /// Use the bundled example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Size of a cell, in pixels
+        #[arg(long, default_value_t = 4)]
+        cell_size: usize,
+        /// Colors to override, like `#=ff0000,o=c2b280,bg=000000`
+        #[arg(long, default_value = "")]
+        palette: Palette,
+        /// Simulation steps between frames
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// Delay between GIF frames, in milliseconds
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+    },
+    /// List all available days and parts
+    List,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// A table with the answers and timings
+    Table,
+    /// One JSON object per line, with the timings in milliseconds
+    Json,
+}
+
+#[cfg(feature = "viz")]
+#[derive(Clone, Copy, ValueEnum)]
+enum ImageFormat {
+    /// A single animated GIF
+    Gif,
+    /// Numbered PNG files
+    Png,
+    /// Numbered binary PPM files
+    Ppm,
+}
+
+#[cfg(feature = "viz")]
+impl From<ImageFormat> for render::Format {
+    fn from(format: ImageFormat) -> render::Format {
+        match format {
+            ImageFormat::Gif => render::Format::Gif,
+            ImageFormat::Png => render::Format::Png,
+            ImageFormat::Ppm => render::Format::Ppm,
+        }
+    }
+}
+
+fn select(day: Option<u8>, part: Option<u8>) -> Vec<&'static Puzzle> {
+    match (day, part) {
+        (None, _) => runner::PUZZLES.iter().collect(),
+        (Some(day), Some(part)) => runner::find(day, part).into_iter().collect(),
+        (Some(day), None) => runner::find_day(day),
+    }
+}
+
+/// Prints the elves ranked by `--top`. The table numbers them from 1 in
+/// input order, the JSON gives their 0-based position like `--stats`.
+#[cfg(feature = "day1")]
+fn print_top(ranked: &[Ranked], format: Format) {
+    match format {
+        Format::Table => {
+            println!("Rank  {:>6}  {:>10}", "Elf", "Calories");
+            println!("{}", "-".repeat(24));
+            for (rank, elf) in ranked.iter().enumerate() {
+                println!(
+                    "{:>4}  {:>6}  {:>10}",
+                    rank + 1,
+                    elf.position + 1,
+                    elf.calories
+                );
+            }
+        }
+        Format::Json => {
+            for (rank, elf) in ranked.iter().enumerate() {
+                let json = serde_json::json!({
+                    "rank": rank + 1,
+                    "position": elf.position,
+                    "calories": elf.calories,
+                });
+                println!("{}", json);
+            }
+        }
+    }
+}
+
+/// Prints the statistics of `--stats`, or `null` in JSON when there are no
+/// elves. The table numbers the elves from 1 in input order.
+#[cfg(feature = "day1")]
+fn print_stats(stats: Option<&Stats>, format: Format) {
+    let stats = match (stats, format) {
+        (_, Format::Json) => return println!("{}", serde_json::to_string(&stats).unwrap()),
+        (Some(stats), Format::Table) => stats,
+        (None, Format::Table) => return println!("No elves in the input"),
+    };
+
+    let rows = [
+        ("Elves", stats.count.to_string()),
+        ("Total", stats.total.to_string()),
+        ("Min", stats.min.to_string()),
+        ("Max", stats.max.to_string()),
+        ("Mean", format!("{:.2}", stats.mean)),
+        ("Median", format!("{:.2}", stats.median)),
+        ("Std dev", format!("{:.2}", stats.std_dev)),
+    ];
+    for (name, value) in rows {
+        println!("{:<10}  {:>12}", name, value);
+    }
+    for percentile in &stats.percentiles {
+        let name = format!("p{}", percentile.percent);
+        println!("{:<10}  {:>12}", name, percentile.calories);
+    }
+    let most = &stats.most_items;
+    println!(
+        "Most items  elf {}, {} items, {} calories",
+        most.position + 1,
+        most.items,
+        most.calories
+    );
+
+    println!();
+    let width = stats.max.to_string().len();
+    let highest = stats
+        .histogram
+        .iter()
+        .map(|bin| bin.count)
+        .max()
+        .unwrap_or(0);
+    for bin in &stats.histogram {
+        // The fullest bin takes 40 columns
+        let bar = "#".repeat(bin.count * 40 / highest.max(1));
+        println!(
+            "{:>width$} - {:>width$}  {} {}",
+            bin.from, bin.to, bar, bin.count
+        );
+    }
+}
+
+/// Whether the day has a simulation, telling which days have one if not.
+#[cfg(feature = "viz")]
+fn is_animated(day: u8) -> bool {
+    if animate::days().any(|d| d == day) {
+        return true;
+    }
+
+    let days: Vec<String> = animate::days().map(|d| d.to_string()).collect();
+    eprintln!("No animation for day {}, try {}", day, days.join(", "));
+    false
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            input,
+            example,
+            format,
+            jobs,
+            mem,
+            #[cfg(feature = "day1")]
+            top,
+            #[cfg(feature = "day1")]
+            stats,
+        } => {
+            let source = match (input, example) {
+                (Some(path), _) => Source::from_arg(&path),
+                (None, true) => Source::Example,
+                (None, false) => Source::Puzzle,
+            };
+
+            #[cfg(feature = "day1")]
+            if top.is_some() || stats {
+                if day != Some(1) {
+                    eprintln!("--top and --stats only apply to day 1");
+                    return ExitCode::FAILURE;
+                }
+
+                // Streamed, so exports too large to load can be ranked
+                let printed = input::open(1, 1, &source).and_then(|input| match top {
+                    Some(n) => day1::read_top_n(input, n).map(|ranked| print_top(&ranked, format)),
+                    None => Elves::new(input)
+                        .collect::<Result<Vec<Elf>, _>>()
+                        .map(|elfs| print_stats(Stats::new(&elfs).as_ref(), format)),
+                });
+                // The elves only see a reader, the file it reads is known here
+                let printed = printed.map_err(|e| match e {
+                    Error::Io {
+                        path: None,
+                        source: e,
+                    } => Error::Io {
+                        path: input::path(1, 1, &source),
+                        source: e,
+                    },
+                    e => e,
+                });
+                if let Err(e) = printed {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+                return ExitCode::SUCCESS;
+            }
+
+            let puzzles = if all {
+                select(None, None)
+            } else {
+                select(day, part)
+            };
+
+            if puzzles.is_empty() {
+                eprintln!("No solution found for the given day and part");
+                return ExitCode::FAILURE;
+            }
+
+            let results = runner::run_parallel(&puzzles, jobs, |puzzle| {
+                input::read(puzzle.day, puzzle.part, &source)
+            });
+
+            let mut failed = false;
+            let mut reports: Vec<Report> = vec![];
+            for (puzzle, result) in puzzles.iter().zip(results) {
+                match result {
+                    Ok(report) => reports.push(report),
+                    Err(e) => {
+                        eprintln!("day {} part {}: {}", puzzle.day, puzzle.part, e);
+                        failed = true;
+                    }
+                }
+            }
+
+            match format {
+                Format::Table if !reports.is_empty() => runner::print_table(&reports, mem),
+                Format::Table => {}
+                Format::Json => runner::print_json(&reports, mem),
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Verify {
+            day,
+            part,
+            example,
+            record,
+            answers,
+        } => {
+            let puzzles = select(day, part);
+            if puzzles.is_empty() {
+                eprintln!("No solution found for the given day and part");
+                return ExitCode::FAILURE;
+            }
+
+            let path = answers.unwrap_or_else(answers::default_path);
+            let mut answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let source = if example {
+                Source::Example
+            } else {
+                Source::Puzzle
+            };
+
+            let mut failed = false;
+            for puzzle in puzzles {
+                let label = format!("day {:>2} part {}", puzzle.day, puzzle.part);
+                // A panicking part fails like any other error
+                let result = input::read(puzzle.day, puzzle.part, &source).and_then(|input| {
+                    runner::run_parallel(&[puzzle], 1, |_| Ok(input.clone()))
+                        .remove(0)
+                        .map(|report| (input, report))
+                });
+                let (input, report) = match result {
+                    Ok(result) => result,
+                    Err(e) => {
+                        println!("{}: {} {}", label, "error".red(), e);
+                        failed = true;
+                        continue;
+                    }
+                };
+
+                let answer = &report.answer;
+                match answers.verify(puzzle.day, puzzle.part, &input, answer) {
+                    Verdict::Correct => println!("{}: {}", label, "ok".green()),
+                    Verdict::Wrong { expected } if record => {
+                        println!(
+                            "{}: {} {} (was {})",
+                            label,
+                            "updated".yellow(),
+                            answer,
+                            expected
+                        );
+                        answers.record(puzzle.day, puzzle.part, &input, answer);
+                    }
+                    Verdict::Wrong { expected } => {
+                        println!(
+                            "{}: {} expected {}, got {}",
+                            label,
+                            "wrong".red(),
+                            expected,
+                            answer
+                        );
+                        failed = true;
+                    }
+                    Verdict::Unknown if record => {
+                        println!("{}: {} {}", label, "recorded".yellow(), answer);
+                        answers.record(puzzle.day, puzzle.part, &input, answer);
+                    }
+                    Verdict::Unknown => println!("{}: {} {}", label, "unknown".yellow(), answer),
+                }
+            }
+
+            if record {
+                if let Err(e) = answers.save(&path) {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            runs,
+            example,
+            baseline,
+            save,
+            threshold,
+        } => {
+            let puzzles = select(day, part);
+            if puzzles.is_empty() {
+                eprintln!("No solution found for the given day and part");
+                return ExitCode::FAILURE;
+            }
+
+            let path = baseline.unwrap_or_else(bench::default_baseline_path);
+            let baseline = match Baseline::load(&path) {
+                Ok(baseline) => baseline,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let source = if example {
+                Source::Example
+            } else {
+                Source::Puzzle
+            };
+
+            let mut failed = false;
+            let mut measurements = vec![];
+            for puzzle in puzzles {
+                match input::read(puzzle.day, puzzle.part, &source)
+                    .and_then(|input| bench::measure(puzzle, &input, runs))
+                {
+                    Ok(measurement) => measurements.push(measurement),
+                    Err(e) => {
+                        eprintln!("day {} part {}: {}", puzzle.day, puzzle.part, e);
+                        failed = true;
+                    }
+                }
+            }
+
+            bench::print_table(&measurements, baseline.as_ref(), threshold / 100.0);
+
+            if save || baseline.is_none() {
+                let mut baseline = baseline.unwrap_or_default();
+                if let Err(e) = baseline.save(&path, &measurements) {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+                eprintln!("Saved baseline to {}", path.display());
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        #[cfg(feature = "fetch")]
+        Command::Fetch { day, force } => {
+            match Fetcher::from_env().and_then(|fetcher| fetcher.fetch(day, force)) {
+                Ok(Fetched::Downloaded(path)) => {
+                    println!("Downloaded the day {} input to {}", day, path.display())
+                }
+                Ok(Fetched::Cached(path)) => println!(
+                    "The day {} input is already cached at {}, use --force to download it again",
+                    day,
+                    path.display()
+                ),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        #[cfg(feature = "serve")]
+        Command::Serve {
+            port,
+            host,
+            max_body,
+            timeout,
+            workers,
+            max_solves,
+        } => {
+            let limits = serve::Limits {
+                max_body,
+                timeout: Duration::from_secs(timeout),
+                max_solves,
+            };
+            match serve::Server::bind(SocketAddr::new(host, port), limits) {
+                Ok(server) => {
+                    eprintln!("Listening on http://{}", server.addr());
+                    server.run(workers);
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::New { day } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            match scaffold::scaffold(root, day) {
+                Ok(files) => {
+                    for file in files {
+                        println!("{}", file.strip_prefix(root).unwrap_or(&file).display());
+                    }
+                    println!("Paste the example into example.txt and its answer into the test");
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Gen { day, size, seed } => match generate::generate(day, size, seed) {
+            Some(input) => print!("{}", input),
+            None => {
+                eprintln!("No generator found for day {}", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Crosscheck {
+            day,
+            part,
+            seeds,
+            size,
+        } => {
+            let puzzles = select(day, part);
+            if puzzles.is_empty() {
+                eprintln!("No solution found for the given day and part");
+                return ExitCode::FAILURE;
+            }
+
+            // Shrinking reruns solutions that panic many times over, the
+            // mismatch reports the panic instead
+            panic::set_hook(Box::new(|_| {}));
+
+            let mut failed = false;
+            for puzzle in puzzles {
+                let label = format!("day {:>2} part {}", puzzle.day, puzzle.part);
+                if !reference::days().any(|d| d == puzzle.day) {
+                    println!("{}: {}", label, "no reference".yellow());
+                    continue;
+                }
+
+                match crosscheck::crosscheck(puzzle, seeds, size) {
+                    Ok(agreed) => println!("{}: {} on {} inputs", label, "ok".green(), agreed),
+                    Err(mismatch) => {
+                        let actual = match &mismatch.actual {
+                            Ok(answer) => format!("got {}", answer),
+                            Err(e) => e.clone(),
+                        };
+                        println!(
+                            "{}: {} expected {}, {}",
+                            label,
+                            "mismatch".red(),
+                            mismatch.expected,
+                            actual
+                        );
+                        println!(
+                            "generated with --seed {} --size {}, shrunk to:",
+                            mismatch.seed, mismatch.size
+                        );
+                        print!("{}", mismatch.input);
+                        failed = true;
+                    }
+                }
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Watch { day, interval } => {
+            if runner::find_day(day).is_empty() {
+                eprintln!("No solution found for day {}", day);
+                return ExitCode::FAILURE;
+            }
+
+            watch::watch(day, Duration::from_millis(interval));
+        }
+        #[cfg(feature = "repl")]
+        Command::Repl {
+            day,
+            part,
+            input,
+            example,
+        } => {
+            if !repl::days().any(|d| d == day) {
+                let days: Vec<String> = repl::days().map(|d| d.to_string()).collect();
+                eprintln!("No commands for day {}, try {}", day, days.join(", "));
+                return ExitCode::FAILURE;
+            }
+
+            let source = match (input, example) {
+                (Some(path), _) => Source::File(path),
+                (None, true) => Source::Example,
+                (None, false) => Source::Puzzle,
+            };
+            let session = input::read(day, part, &source)
+                .and_then(|input| repl::session(day, part, &input).expect("the day has commands"))
+                .and_then(repl::run);
+            if let Err(e) = session {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        #[cfg(feature = "viz")]
+        Command::Animate {
+            day,
+            part,
+            input,
+            example,
+            fps,
+            speed,
+        } => {
+            let source = match (input, example) {
+                (Some(path), _) => Source::from_arg(&path),
+                (None, true) => Source::Example,
+                (None, false) => Source::Puzzle,
+            };
+
+            if !is_animated(day) {
+                return ExitCode::FAILURE;
+            }
+
+            let simulation = input::read(day, part, &source).and_then(|input| {
+                animate::simulation(day, part, &input).expect("the day has an animation")
+            });
+            let mut simulation = match simulation {
+                Ok(simulation) => simulation,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            if let Err(e) = Animator::new(fps, speed).play(simulation.as_mut()) {
+                eprintln!("couldn't animate: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        #[cfg(feature = "viz")]
+        Command::Export {
+            day,
+            part,
+            out,
+            format,
+            example,
+            cell_size,
+            palette,
+            every,
+            delay,
+        } => {
+            if !is_animated(day) {
+                return ExitCode::FAILURE;
+            }
+
+            let source = if example {
+                Source::Example
+            } else {
+                Source::Puzzle
+            };
+            let export = Export {
+                format: format.into(),
+                palette,
+                cell_size,
+                every,
+                delay: Duration::from_millis(delay),
+            };
+
+            let frames = input::read(day, part, &source)
+                .and_then(|input| {
+                    animate::simulation(day, part, &input).expect("the day has an animation")
+                })
+                .and_then(|mut simulation| render::export(simulation.as_mut(), &out, &export));
+            match frames {
+                Ok(frames) => eprintln!("Wrote {} frames to {}", frames, out.display()),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::List => {
+            for puzzle in runner::PUZZLES {
+                println!("day {:>2} part {}", puzzle.day, puzzle.part);
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
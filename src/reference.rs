@@ -0,0 +1,740 @@
+//! Naive solutions of every day, written to be obviously right rather than
+//! fast. They share nothing with the real solutions, not even the parsing,
+//! so [`crosscheck`](crate::crosscheck) can compare the two.
+//!
+//! A reference answers `None` for inputs the puzzle doesn't define an answer
+//! for, like a day 1 part 2 with less than three elves.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+type Reference = fn(&str, u8) -> Option<String>;
+
+const REFERENCES: &[(u8, Reference)] = &[
+    (1, day1),
+    (2, day2),
+    (3, day3),
+    (4, day4),
+    (5, day5),
+    (6, day6),
+    (7, day7),
+    (8, day8),
+    (9, day9),
+    (10, day10),
+    (11, day11),
+    (12, day12),
+    (13, day13),
+    (14, day14),
+];
+
+/// The answer to the part, `None` if the day has no reference or the input
+/// has no answer.
+pub fn solve(day: u8, part: u8, input: &str) -> Option<String> {
+    let (_, reference) = REFERENCES.iter().find(|(d, _)| *d == day)?;
+    reference(input, part)
+}
+
+pub fn days() -> impl Iterator<Item = u8> {
+    REFERENCES.iter().map(|(day, _)| *day)
+}
+
+fn day1(input: &str, part: u8) -> Option<String> {
+    let mut elves = vec![0u64];
+    for line in input.lines() {
+        if line.is_empty() {
+            elves.push(0);
+        } else {
+            *elves.last_mut()? += line.parse::<u64>().ok()?;
+        }
+    }
+
+    elves.sort();
+    elves.reverse();
+    let top = if part == 1 { 1 } else { 3 };
+    if elves.len() < top {
+        return None;
+    }
+    Some(elves[..top].iter().sum::<u64>().to_string())
+}
+
+fn day2(input: &str, part: u8) -> Option<String> {
+    // Rock, paper and scissors are 0, 1 and 2, each beating the one before
+    let outcome = |mine: usize, theirs: usize| (mine + 4 - theirs) % 3 * 3;
+
+    let mut score = 0;
+    for line in input.lines() {
+        let (theirs, column) = line.split_once(' ')?;
+        let theirs = ["A", "B", "C"].iter().position(|&s| s == theirs)?;
+        let column = ["X", "Y", "Z"].iter().position(|&s| s == column)?;
+
+        let mine = if part == 1 {
+            column
+        } else {
+            (0..3).find(|&mine| outcome(mine, theirs) == column * 3)?
+        };
+        score += mine + 1 + outcome(mine, theirs);
+    }
+
+    Some(score.to_string())
+}
+
+fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+fn day3(input: &str, part: u8) -> Option<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let groups: Vec<Vec<&str>> = if part == 1 {
+        lines
+            .iter()
+            .map(|line| {
+                let (left, right) = line.split_at(line.len() / 2);
+                (left.len() == right.len()).then(|| vec![left, right])
+            })
+            .collect::<Option<_>>()?
+    } else {
+        if !lines.len().is_multiple_of(3) {
+            return None;
+        }
+        lines.chunks(3).map(|group| group.to_vec()).collect()
+    };
+
+    let mut sum = 0;
+    for group in groups {
+        let common = group[0]
+            .chars()
+            .find(|&item| group.iter().all(|items| items.contains(item)))?;
+        sum += priority(common)?;
+    }
+
+    Some(sum.to_string())
+}
+
+fn day4(input: &str, part: u8) -> Option<String> {
+    let sections = |range: &str| -> Option<HashSet<u64>> {
+        let (start, end) = range.split_once('-')?;
+        let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+        (start <= end).then(|| (start..=end).collect())
+    };
+
+    let mut count = 0;
+    for line in input.lines() {
+        let (first, second) = line.split_once(',')?;
+        let (first, second) = (sections(first)?, sections(second)?);
+
+        let counts = if part == 1 {
+            first.is_subset(&second) || second.is_subset(&first)
+        } else {
+            !first.is_disjoint(&second)
+        };
+        count += counts as usize;
+    }
+
+    Some(count.to_string())
+}
+
+fn day5(input: &str, part: u8) -> Option<String> {
+    let (drawing, moves) = input.split_once("\n\n")?;
+    let mut rows: Vec<&str> = drawing.lines().collect();
+    let numbers = rows.pop()?;
+    let n_stacks = numbers.split_whitespace().count();
+
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; n_stacks];
+    for row in rows.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(1 + 4 * i) {
+                Some(' ') | None => {}
+                Some(label) => stack.push(label),
+            }
+        }
+    }
+
+    for line in moves.lines() {
+        let words: Vec<&str> = line.split(' ').collect();
+        let ["move", amount, "from", from, "to", to] = words[..] else {
+            return None;
+        };
+        let amount: usize = amount.parse().ok()?;
+        let from = from.parse::<usize>().ok()?.checked_sub(1)?;
+        let to = to.parse::<usize>().ok()?.checked_sub(1)?;
+
+        let height = stacks.get(from)?.len().checked_sub(amount)?;
+        let mut moved = stacks[from].split_off(height);
+        if part == 1 {
+            moved.reverse();
+        }
+        stacks.get_mut(to)?.extend(moved);
+    }
+
+    Some(stacks.iter().filter_map(|stack| stack.last()).collect())
+}
+
+fn day6(input: &str, part: u8) -> Option<String> {
+    let stream: Vec<char> = input.lines().next()?.chars().collect();
+    let marker = |length: usize| {
+        (length..=stream.len()).find(|&end| {
+            let window = &stream[end - length..end];
+            window
+                .iter()
+                .enumerate()
+                .all(|(i, c)| !window[i + 1..].contains(c))
+        })
+    };
+
+    // A stream missing either marker is invalid for both parts
+    let (packet, message) = (marker(4)?, marker(14)?);
+    Some(if part == 1 { packet } else { message }.to_string())
+}
+
+fn day7(input: &str, part: u8) -> Option<String> {
+    let mut cwd: Vec<&str> = vec![];
+    let mut directories: HashSet<String> = HashSet::from(["/".to_string()]);
+    let mut files: HashMap<String, u64> = HashMap::new();
+    let path = |cwd: &[&str]| {
+        format!(
+            "/{}",
+            cwd.iter()
+                .map(|dir| format!("{}/", dir))
+                .collect::<String>()
+        )
+    };
+
+    for line in input.lines() {
+        let words: Vec<&str> = line.split(' ').collect();
+        match words[..] {
+            ["$", "cd", "/"] => cwd.clear(),
+            ["$", "cd", ".."] => {
+                cwd.pop()?;
+            }
+            ["$", "cd", dir] => {
+                cwd.push(dir);
+                if !directories.contains(&path(&cwd)) {
+                    return None;
+                }
+            }
+            ["$", "ls"] => {}
+            ["dir", dir] => {
+                directories.insert(format!("{}{}/", path(&cwd), dir));
+            }
+            [size, name] => {
+                files.insert(format!("{}{}", path(&cwd), name), size.parse().ok()?);
+            }
+            _ => return None,
+        }
+    }
+
+    let sizes: Vec<u64> = directories
+        .iter()
+        .map(|dir| {
+            files
+                .iter()
+                .filter(|(file, _)| file.starts_with(dir.as_str()))
+                .map(|(_, size)| size)
+                .sum()
+        })
+        .collect();
+
+    if part == 1 {
+        let small = sizes.iter().filter(|&&size| size <= 100000);
+        return Some(small.sum::<u64>().to_string());
+    }
+
+    let used: u64 = files.values().sum();
+    let needed = (used + 30000000).checked_sub(70000000).filter(|&n| n > 0)?;
+    let smallest = sizes.iter().filter(|&&size| size >= needed).min()?;
+    Some(smallest.to_string())
+}
+
+fn day8(input: &str, part: u8) -> Option<String> {
+    let grid: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10)).collect())
+        .collect::<Option<_>>()?;
+    let height = grid.len() as i64;
+    let width = grid.first()?.len() as i64;
+    if width == 0 || grid.iter().any(|row| row.len() as i64 != width) {
+        return None;
+    }
+
+    let mut visible = 0;
+    let mut best_score = 0;
+    for y in 0..height {
+        for x in 0..width {
+            let tree = grid[y as usize][x as usize];
+            let mut seen_from_outside = false;
+            let mut score = 1;
+
+            for (dx, dy) in [(0, -1), (-1, 0), (0, 1), (1, 0)] {
+                let (mut cx, mut cy) = (x + dx, y + dy);
+                let mut distance = 0;
+                let mut blocked = false;
+                while (0..width).contains(&cx) && (0..height).contains(&cy) {
+                    distance += 1;
+                    if grid[cy as usize][cx as usize] >= tree {
+                        blocked = true;
+                        break;
+                    }
+                    cx += dx;
+                    cy += dy;
+                }
+
+                seen_from_outside |= !blocked;
+                score *= distance;
+            }
+
+            visible += seen_from_outside as usize;
+            best_score = best_score.max(score);
+        }
+    }
+
+    Some(if part == 1 { visible } else { best_score }.to_string())
+}
+
+fn day9(input: &str, part: u8) -> Option<String> {
+    let touching = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).abs() <= 1 && (a.1 - b.1).abs() <= 1;
+    let n_knots = if part == 1 { 2 } else { 10 };
+    let mut knots = vec![(0i64, 0i64); n_knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for line in input.lines() {
+        let (direction, amount) = line.split_once(' ')?;
+        let (dx, dy) = match direction {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => return None,
+        };
+
+        for _ in 0..amount.parse::<u32>().ok()? {
+            knots[0].0 += dx;
+            knots[0].1 += dy;
+            for i in 1..n_knots {
+                let (head, knot) = (knots[i - 1], knots[i]);
+                if touching(head, knot) {
+                    continue;
+                }
+                // The neighbour of the knot touching the head and closest to it
+                knots[i] = (-1..=1)
+                    .flat_map(|dx| (-1..=1).map(move |dy| (knot.0 + dx, knot.1 + dy)))
+                    .filter(|&next| touching(head, next))
+                    .min_by_key(|next| (head.0 - next.0).abs() + (head.1 - next.1).abs())?;
+            }
+            visited.insert(knots[n_knots - 1]);
+        }
+    }
+
+    Some(visited.len().to_string())
+}
+
+fn day10(input: &str, part: u8) -> Option<String> {
+    // The value of X during each cycle
+    let mut x = 1i64;
+    let mut during = vec![];
+    for line in input.lines() {
+        match line.split_once(' ') {
+            None if line == "noop" => during.push(x),
+            Some(("addx", value)) => {
+                during.extend([x, x]);
+                x += value.parse::<i64>().ok()?;
+            }
+            _ => return None,
+        }
+    }
+
+    if part == 1 {
+        let strength: i64 = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .filter_map(|&cycle| Some(cycle as i64 * during.get(cycle - 1)?))
+            .sum();
+        return Some(strength.to_string());
+    }
+
+    let rows: Vec<String> = (0..6)
+        .map(|row| {
+            (0..40)
+                .map(|column| match during.get(row * 40 + column) {
+                    Some(x) if (column as i64 - x).abs() <= 1 => '#',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    Some(rows.join("\n"))
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    /// `None` standing for the old worry level.
+    operands: (Option<u64>, Option<u64>),
+    multiplies: bool,
+    divisor: u64,
+    targets: (usize, usize),
+}
+
+impl Monkey {
+    fn parse(block: &str) -> Option<Monkey> {
+        let lines: Vec<&str> = block.lines().map(str::trim).collect();
+        let [_, items, operation, test, if_true, if_false] = lines[..] else {
+            return None;
+        };
+
+        let items = items
+            .strip_prefix("Starting items:")?
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| item.trim().parse().ok())
+            .collect::<Option<_>>()?;
+
+        let operand = |word: &str| match word {
+            "old" => Some(None),
+            n => n.parse().ok().map(Some),
+        };
+        let words: Vec<&str> = operation
+            .strip_prefix("Operation: new =")?
+            .split_whitespace()
+            .collect();
+        let [left, operator, right] = words[..] else {
+            return None;
+        };
+
+        Some(Monkey {
+            items,
+            operands: (operand(left)?, operand(right)?),
+            multiplies: match operator {
+                "*" => true,
+                "+" => false,
+                _ => return None,
+            },
+            divisor: test
+                .strip_prefix("Test: divisible by ")?
+                .parse()
+                .ok()
+                .filter(|&d| d > 0)?,
+            targets: (
+                if_true
+                    .strip_prefix("If true: throw to monkey ")?
+                    .parse()
+                    .ok()?,
+                if_false
+                    .strip_prefix("If false: throw to monkey ")?
+                    .parse()
+                    .ok()?,
+            ),
+        })
+    }
+
+    /// The new worry level, wide enough for two of the old ones.
+    fn operate(&self, old: u64) -> u128 {
+        let operand = |operand: Option<u64>| operand.unwrap_or(old) as u128;
+        let (left, right) = (operand(self.operands.0), operand(self.operands.1));
+        if self.multiplies {
+            left * right
+        } else {
+            left + right
+        }
+    }
+
+    fn target(&self, divisible: bool) -> usize {
+        if divisible {
+            self.targets.0
+        } else {
+            self.targets.1
+        }
+    }
+}
+
+fn day11(input: &str, part: u8) -> Option<String> {
+    let monkeys: Vec<Monkey> = input
+        .split("\n\n")
+        .map(Monkey::parse)
+        .collect::<Option<_>>()?;
+    let n = monkeys.len();
+    if n < 2 || monkeys.iter().any(|m| m.targets.0 >= n || m.targets.1 >= n) {
+        return None;
+    }
+
+    let mut inspections = vec![0u64; n];
+    if part == 1 {
+        let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+        for _ in 0..20 {
+            for (i, monkey) in monkeys.iter().enumerate() {
+                for old in std::mem::take(&mut items[i]) {
+                    inspections[i] += 1;
+                    let new = u64::try_from(monkey.operate(old)).ok()? / 3;
+                    items[monkey.target(new % monkey.divisor == 0)].push(new);
+                }
+            }
+        }
+    } else {
+        // Without the relief the worry levels grow without bounds, but the
+        // tests only need them modulo each monkey's divisor
+        let mut items: Vec<Vec<Vec<u64>>> = monkeys
+            .iter()
+            .map(|m| {
+                m.items
+                    .iter()
+                    .map(|&item| monkeys.iter().map(|d| item % d.divisor).collect())
+                    .collect()
+            })
+            .collect();
+        for _ in 0..10000 {
+            for (i, monkey) in monkeys.iter().enumerate() {
+                for old in std::mem::take(&mut items[i]) {
+                    inspections[i] += 1;
+                    let new: Vec<u64> = old
+                        .iter()
+                        .zip(&monkeys)
+                        .map(|(&residue, d)| (monkey.operate(residue) % d.divisor as u128) as u64)
+                        .collect();
+                    items[monkey.target(new[i] == 0)].push(new);
+                }
+            }
+        }
+    }
+
+    inspections.sort();
+    inspections.reverse();
+    Some((inspections[0] * inspections[1]).to_string())
+}
+
+fn day12(input: &str, part: u8) -> Option<String> {
+    let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let height = grid.len();
+    let width = grid.first()?.len();
+    if grid.iter().any(|row| row.len() != width) {
+        return None;
+    }
+
+    let elevation = |c: char| match c {
+        'S' => Some(0),
+        'E' => Some(25),
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        _ => None,
+    };
+    let mut elevations = HashMap::new();
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            elevations.insert((x, y), elevation(c)?);
+        }
+    }
+    let find = |wanted: char| {
+        let y = grid.iter().position(|row| row.contains(&wanted))?;
+        Some((grid[y].iter().position(|&c| c == wanted)?, y))
+    };
+    let (start, end) = (find('S')?, find('E')?);
+
+    // Steps to the end, relaxed until nothing gets shorter
+    let mut steps: HashMap<(usize, usize), u32> = HashMap::from([(end, 0)]);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for y in 0..height {
+            for x in 0..width {
+                let neighbours = [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ];
+                for next in neighbours {
+                    let (Some(&to), Some(&through)) = (elevations.get(&next), steps.get(&next))
+                    else {
+                        continue;
+                    };
+                    if to <= elevations[&(x, y)] + 1
+                        && steps.get(&(x, y)).is_none_or(|&s| through + 1 < s)
+                    {
+                        steps.insert((x, y), through + 1);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    let fewest = if part == 1 {
+        steps.get(&start).copied()
+    } else {
+        steps
+            .iter()
+            .filter(|(point, _)| elevations[point] == 0)
+            .map(|(_, &s)| s)
+            .min()
+    };
+    fewest.map(|steps| steps.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Packet {
+    Integer(u64),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn parse(text: &str) -> Option<Packet> {
+        let (packet, rest) = Packet::parse_prefix(text)?;
+        (rest.is_empty() && matches!(packet, Packet::List(_))).then_some(packet)
+    }
+
+    fn parse_prefix(text: &str) -> Option<(Packet, &str)> {
+        if let Some(mut rest) = text.strip_prefix('[') {
+            let mut items = vec![];
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Some((Packet::List(items), rest));
+            }
+            loop {
+                let (item, after) = Packet::parse_prefix(rest)?;
+                items.push(item);
+                if let Some(after) = after.strip_prefix(',') {
+                    rest = after;
+                } else {
+                    return Some((Packet::List(items), after.strip_prefix(']')?));
+                }
+            }
+        }
+
+        let digits = text.chars().take_while(char::is_ascii_digit).count();
+        let value = text[..digits].parse().ok()?;
+        Some((Packet::Integer(value), &text[digits..]))
+    }
+
+    fn compare(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => {
+                for (left, right) in left.iter().zip(right) {
+                    match left.compare(right) {
+                        Ordering::Equal => {}
+                        decided => return decided,
+                    }
+                }
+                left.len().cmp(&right.len())
+            }
+            (Packet::Integer(_), Packet::List(_)) => {
+                Packet::List(vec![self.clone()]).compare(other)
+            }
+            (Packet::List(_), Packet::Integer(_)) => {
+                self.compare(&Packet::List(vec![other.clone()]))
+            }
+        }
+    }
+}
+
+fn day13(input: &str, part: u8) -> Option<String> {
+    let packets: Vec<Packet> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(Packet::parse)
+        .collect::<Option<_>>()?;
+    if !packets.len().is_multiple_of(2) {
+        return None;
+    }
+
+    if part == 1 {
+        let mut sum = 0;
+        for (i, pair) in packets.chunks(2).enumerate() {
+            match pair[0].compare(&pair[1]) {
+                Ordering::Less => sum += i + 1,
+                Ordering::Greater => {}
+                // Equal packets have no order
+                Ordering::Equal => return None,
+            }
+        }
+        return Some(sum.to_string());
+    }
+
+    // Where the dividers end up is how many packets sort before them
+    let dividers = [Packet::parse("[[2]]")?, Packet::parse("[[6]]")?];
+    // Packets equal to a divider could go on either side of it
+    let ties = packets.iter().any(|packet| {
+        dividers
+            .iter()
+            .any(|d| packet.compare(d) == Ordering::Equal)
+    });
+    if ties {
+        return None;
+    }
+    let before = |divider: &Packet| {
+        packets
+            .iter()
+            .filter(|packet| packet.compare(divider) == Ordering::Less)
+            .count()
+    };
+    Some(((1 + before(&dividers[0])) * (2 + before(&dividers[1]))).to_string())
+}
+
+fn day14(input: &str, part: u8) -> Option<String> {
+    let mut blocked: HashSet<(i64, i64)> = HashSet::new();
+    for line in input.lines() {
+        let corners: Vec<(i64, i64)> = line
+            .split(" -> ")
+            .map(|corner| {
+                let (x, y) = corner.split_once(',')?;
+                Some((x.parse().ok()?, y.parse().ok()?))
+            })
+            .collect::<Option<_>>()?;
+
+        for segment in corners.windows(2) {
+            let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+            if x1 != x2 && y1 != y2 {
+                return None;
+            }
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    blocked.insert((x, y));
+                }
+            }
+        }
+    }
+    let bottom = blocked.iter().map(|&(_, y)| y).max()?;
+    let floor = bottom + 2;
+
+    let mut resting = 0;
+    while !blocked.contains(&(500, 0)) {
+        let mut sand = (500, 0);
+        loop {
+            let below = [(0, 1), (-1, 1), (1, 1)]
+                .into_iter()
+                .map(|(dx, dy)| (sand.0 + dx, sand.1 + dy))
+                .find(|next| !blocked.contains(next) && next.1 < floor);
+            match below {
+                Some(next) => sand = next,
+                None => break,
+            }
+            if part == 1 && sand.1 > bottom {
+                return Some(resting.to_string());
+            }
+        }
+        blocked.insert(sand);
+        resting += 1;
+    }
+
+    Some(resting.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input, runner};
+
+    #[test]
+    fn references_agree_on_the_examples() {
+        for puzzle in runner::PUZZLES {
+            let example = input::read(puzzle.day, puzzle.part, &input::Source::Example).unwrap();
+            assert_eq!(
+                solve(puzzle.day, puzzle.part, &example),
+                Some(puzzle.run(&example).unwrap().answer),
+                "day {} part {}",
+                puzzle.day,
+                puzzle.part
+            );
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{animate::Simulation, error::Error};
+
+pub type Rgb = [u8; 3];
+
+/// The colors characters of a frame are drawn with.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    background: Rgb,
+    /// Color of characters without one.
+    fallback: Rgb,
+}
+
+impl Default for Palette {
+    /// Colors fitting the frames of the bundled simulations, with heights
+    /// from `a` to `z` going from dark green to white.
+    fn default() -> Palette {
+        let mut colors = HashMap::from([
+            ('#', [170, 170, 170]),
+            ('o', [230, 190, 90]),
+            ('+', [255, 255, 255]),
+            ('H', [230, 60, 60]),
+            ('T', [240, 140, 60]),
+            ('s', [90, 140, 240]),
+            ('S', [90, 140, 240]),
+            ('E', [230, 60, 60]),
+        ]);
+        for (i, knot) in ('1'..='9').enumerate() {
+            colors.insert(knot, [240, 140 + 10 * i as u8, 60]);
+        }
+        for (i, height) in ('a'..='z').enumerate() {
+            let mix = |from: u8, to: u8| from + ((to - from) as usize * i / 25) as u8;
+            colors.insert(height, [mix(20, 230), mix(60, 240), mix(30, 230)]);
+        }
+
+        Palette {
+            colors,
+            background: [20, 20, 30],
+            fallback: [255, 0, 255],
+        }
+    }
+}
+
+impl Palette {
+    pub fn color(&self, c: char) -> Rgb {
+        match c {
+            '.' | ' ' => self.background,
+            _ => self.colors.get(&c).copied().unwrap_or(self.fallback),
+        }
+    }
+
+    /// Every color the palette can draw with.
+    fn all_colors(&self) -> Vec<Rgb> {
+        let mut colors: Vec<Rgb> = self.colors.values().copied().collect();
+        colors.extend([self.background, self.fallback]);
+        colors.sort();
+        colors.dedup();
+
+        colors
+    }
+}
+
+/// The default palette with colors replaced by a comma separated list of
+/// `character=rrggbb`, like `#=ff0000,o=c2b280`. `bg` stands for the
+/// background, which `.` and spaces are drawn with.
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Palette::default();
+
+        for entry in s.split(',').filter(|entry| !entry.is_empty()) {
+            let (key, hex) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected 'character=rrggbb', got '{}'", entry))?;
+            let color = parse_hex(hex)?;
+
+            let mut chars = key.chars();
+            match (key, chars.next(), chars.next()) {
+                ("bg", _, _) => palette.background = color,
+                (_, Some(c), None) => {
+                    palette.colors.insert(c, color);
+                }
+                _ => {
+                    return Err(format!(
+                        "expected a single character or 'bg', got '{}'",
+                        key
+                    ))
+                }
+            }
+        }
+
+        Ok(palette)
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Rgb, String> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 {
+        return Err(format!("expected a color as rrggbb, got '{}'", hex));
+    }
+
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+            .ok_or_else(|| format!("invalid color '{}'", hex))
+    };
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// An RGB image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws every character of the frame as a `cell_size` square, shorter
+    /// lines being padded with the background.
+    pub fn from_frame(frame: &str, palette: &Palette, cell_size: usize) -> Image {
+        let lines: Vec<Vec<char>> = frame.lines().map(|line| line.chars().collect()).collect();
+        let columns = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let cell_size = cell_size.max(1);
+
+        let width = columns * cell_size;
+        let height = lines.len() * cell_size;
+        let mut pixels = vec![palette.background; width * height];
+        for (row, line) in lines.iter().enumerate() {
+            for (column, &c) in line.iter().enumerate() {
+                let color = palette.color(c);
+                for y in row * cell_size..(row + 1) * cell_size {
+                    let start = y * width + column * cell_size;
+                    pixels[start..start + cell_size].fill(color);
+                }
+            }
+        }
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Writes the image as a binary PPM.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+}
+
+/// An animated GIF looping forever. Frames are cropped or padded to the size
+/// of the first one and can only use the colors of the palette.
+pub struct Gif<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    indices: HashMap<Rgb, u8>,
+    background: u8,
+    /// Delay between frames, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> Gif<W> {
+    pub fn new(
+        out: W,
+        width: usize,
+        height: usize,
+        palette: &Palette,
+        delay: Duration,
+    ) -> io::Result<Gif<W>> {
+        let colors = palette.all_colors();
+        if colors.len() > 256 {
+            return Err(io::Error::other("a GIF can't have more than 256 colors"));
+        }
+        let width = u16::try_from(width).map_err(io::Error::other)?;
+        let height = u16::try_from(height).map_err(io::Error::other)?;
+
+        let flat: Vec<u8> = colors.iter().flatten().copied().collect();
+        let mut encoder = gif::Encoder::new(out, width, height, &flat).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        let indices: HashMap<Rgb, u8> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, &color)| (color, i as u8))
+            .collect();
+
+        Ok(Gif {
+            encoder,
+            width,
+            height,
+            background: indices[&palette.background],
+            indices,
+            delay: (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16,
+        })
+    }
+
+    pub fn add(&mut self, image: &Image) -> io::Result<()> {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let mut buffer = vec![self.background; width * height];
+        for y in 0..height.min(image.height) {
+            for x in 0..width.min(image.width) {
+                let color = image.pixels[y * image.width + x];
+                buffer[y * width + x] = self.indices.get(&color).copied().unwrap_or(0);
+            }
+        }
+
+        let frame = gif::Frame {
+            width: self.width,
+            height: self.height,
+            delay: self.delay,
+            buffer: buffer.into(),
+            ..Default::default()
+        };
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Numbered PPM files in a directory.
+    Ppm,
+    /// Numbered PNG files in a directory.
+    Png,
+    /// A single animated GIF.
+    Gif,
+}
+
+#[derive(Debug, Clone)]
+pub struct Export {
+    pub format: Format,
+    pub palette: Palette,
+    /// Size of the square each character is drawn as, in pixels.
+    pub cell_size: usize,
+    /// Number of simulation steps between frames.
+    pub every: usize,
+    /// Delay between the frames of a GIF.
+    pub delay: Duration,
+}
+
+/// Plays the simulation to the end, rendering a frame every `every` steps
+/// and the last one. Image sequences are written as `frame-00000.png` and so
+/// on into the directory at `path`, GIFs to the file at `path`. Returns the
+/// number of frames written.
+pub fn export(
+    simulation: &mut dyn Simulation,
+    path: &Path,
+    export: &Export,
+) -> Result<usize, Error> {
+    let dir = match export.format {
+        Format::Gif => path.parent().unwrap_or(Path::new("")),
+        _ => path,
+    };
+    if !dir.as_os_str().is_empty() {
+        fs::create_dir_all(dir).map_err(|source| Error::Io {
+            path: Some(dir.to_path_buf()),
+            source,
+        })?;
+    }
+
+    let mut gif = None;
+    let mut frames = 0;
+    loop {
+        let image = Image::from_frame(&simulation.frame(), &export.palette, export.cell_size);
+        write_frame(&image, frames, path, export, &mut gif)?;
+        frames += 1;
+
+        let mut steps = 0;
+        while steps < export.every.max(1) && simulation.step() {
+            steps += 1;
+        }
+        if steps == 0 {
+            return Ok(frames);
+        }
+    }
+}
+
+fn write_frame(
+    image: &Image,
+    index: usize,
+    path: &Path,
+    export: &Export,
+    gif: &mut Option<Gif<BufWriter<File>>>,
+) -> Result<(), Error> {
+    let (path, result) = match (export.format, gif) {
+        (Format::Gif, Some(gif)) => (path.to_path_buf(), gif.add(image)),
+        (Format::Gif, gif) => {
+            let result = File::create(path).and_then(|file| {
+                let out = BufWriter::new(file);
+                let new = Gif::new(
+                    out,
+                    image.width,
+                    image.height,
+                    &export.palette,
+                    export.delay,
+                )?;
+                gif.insert(new).add(image)
+            });
+            (path.to_path_buf(), result)
+        }
+        (Format::Ppm, _) => {
+            let path = path.join(format!("frame-{:05}.ppm", index));
+            let result = File::create(&path).and_then(|file| image.write_ppm(BufWriter::new(file)));
+            (path, result)
+        }
+        (Format::Png, _) => {
+            let path = path.join(format!("frame-{:05}.png", index));
+            let result = File::create(&path).and_then(|file| image.write_png(BufWriter::new(file)));
+            (path, result)
+        }
+    };
+
+    result.map_err(|source| Error::Io {
+        path: Some(path),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_characters_as_cells() {
+        let palette: Palette = "#=ff0000,bg=000000".parse().unwrap();
+        let image = Image::from_frame("#.\n#", &palette, 2);
+
+        assert_eq!((image.width, image.height), (4, 4));
+        let red = [255, 0, 0];
+        let black = [0, 0, 0];
+        assert_eq!(image.pixels[0..4], [red, red, black, black]);
+        assert_eq!(image.pixels[12..16], [red, red, black, black]);
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let image = Image::from_frame("#", &Palette::default(), 1);
+        let mut out = vec![];
+        image.write_ppm(&mut out).unwrap();
+
+        assert_eq!(out, b"P6\n1 1\n255\n\xaa\xaa\xaa");
+    }
+
+    #[test]
+    fn writes_gif_frames_of_the_first_size() {
+        let palette = Palette::default();
+        let mut out = vec![];
+        {
+            let mut gif = Gif::new(&mut out, 2, 1, &palette, Duration::from_millis(100)).unwrap();
+            gif.add(&Image::from_frame("#o", &palette, 1)).unwrap();
+            gif.add(&Image::from_frame("#o#\n#", &palette, 1)).unwrap();
+        }
+
+        assert_eq!(&out[..6], b"GIF89a");
+        assert_eq!(&out[6..10], [2, 0, 1, 0]);
+        assert_eq!(out.last(), Some(&0x3b));
+    }
+
+    #[test]
+    fn rejects_malformed_palettes() {
+        assert!("#=ff00".parse::<Palette>().is_err());
+        assert!("ab=ff0000".parse::<Palette>().is_err());
+        assert!("#".parse::<Palette>().is_err());
+    }
+}
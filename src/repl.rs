@@ -0,0 +1,298 @@
+use std::str::FromStr;
+#[cfg(feature = "repl")]
+use std::{cell::RefCell, io, path::PathBuf, rc::Rc};
+
+#[cfg(feature = "repl")]
+use rustyline::{
+    completion::Completer, error::ReadlineError, highlight::Highlighter, hint::Hinter,
+    history::FileHistory, validate::Validator, Context, Editor, Helper,
+};
+
+use crate::{error::Error, *};
+
+/// A command of a session, shown by `help`.
+pub struct Command {
+    pub name: &'static str,
+    /// The arguments, like `<x> <y>`.
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// Commands querying the parsed input of a day.
+pub trait Session {
+    fn commands(&self) -> &'static [Command];
+
+    /// Runs one of the [`commands`](Session::commands), returning what to
+    /// print or why it failed.
+    fn eval(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+
+    /// Values starting with `arg` that the command accepts, to complete it.
+    fn complete(&self, _command: &str, _arg: &str) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Commands every session understands.
+const BUILTINS: &[Command] = &[
+    Command {
+        name: "help",
+        args: "",
+        help: "list the commands",
+    },
+    Command {
+        name: "quit",
+        args: "",
+        help: "leave, like Ctrl-D",
+    },
+];
+
+type Builder = fn(&str, u8) -> Result<Box<dyn Session>, error::ParseError>;
+
+const SESSIONS: &[(u8, Builder)] = &[
+    #[cfg(feature = "day7")]
+    (7, day7::explore),
+    #[cfg(feature = "day8")]
+    (8, day8::explore),
+    #[cfg(feature = "day10")]
+    (10, day10::explore),
+    #[cfg(feature = "day11")]
+    (11, day11::explore),
+    #[cfg(feature = "day13")]
+    (13, day13::explore),
+];
+
+/// A session on the parsed input of the day, `None` if the day has no
+/// commands.
+pub fn session(day: u8, part: u8, input: &str) -> Option<Result<Box<dyn Session>, Error>> {
+    SESSIONS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, builder)| builder(input, part).map_err(|source| Error::Parse { day, source }))
+}
+
+pub fn days() -> impl Iterator<Item = u8> {
+    SESSIONS.iter().map(|(day, _)| *day)
+}
+
+/// The argument at `index`, parsed.
+pub fn arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, String> {
+    let arg = args
+        .get(index)
+        .ok_or_else(|| format!("missing the {} argument", name))?;
+    arg.parse()
+        .map_err(|_| format!("'{}' isn't a valid {}", arg, name))
+}
+
+/// The argument at `index` parsed, or `default` when it's missing.
+pub fn arg_or<T: FromStr>(
+    args: &[&str],
+    index: usize,
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    match args.get(index) {
+        Some(_) => arg(args, index, name),
+        None => Ok(default),
+    }
+}
+
+pub enum Outcome {
+    Print(String),
+    Quit,
+}
+
+/// Runs a line typed in the session.
+pub fn eval_line(session: &mut dyn Session, line: &str) -> Outcome {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&command, args)) = words.split_first() else {
+        return Outcome::Print(String::new());
+    };
+
+    match command {
+        "quit" | "exit" => Outcome::Quit,
+        "help" => Outcome::Print(help(session)),
+        _ if session.commands().iter().any(|c| c.name == command) => {
+            match session.eval(command, args) {
+                Ok(output) => Outcome::Print(output),
+                Err(message) => Outcome::Print(format!("error: {}", message)),
+            }
+        }
+        _ => Outcome::Print(format!("unknown command '{}', try 'help'", command)),
+    }
+}
+
+fn help(session: &dyn Session) -> String {
+    let commands: Vec<&Command> = session.commands().iter().chain(BUILTINS).collect();
+    let usages: Vec<String> = commands
+        .iter()
+        .map(|c| format!("{} {}", c.name, c.args).trim_end().to_string())
+        .collect();
+    let width = usages.iter().map(|usage| usage.len()).max().unwrap_or(0);
+
+    let lines: Vec<String> = commands
+        .iter()
+        .zip(&usages)
+        .map(|(command, usage)| format!("{:<width$}  {}", usage, command.help))
+        .collect();
+    lines.join("\n")
+}
+
+/// Completes command names, then their arguments through the session.
+#[cfg(feature = "repl")]
+struct Completion {
+    session: Rc<RefCell<Box<dyn Session>>>,
+}
+
+#[cfg(feature = "repl")]
+impl Completer for Completion {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line.rfind(' ').map_or(0, |space| space + 1);
+        let word = &line[start..];
+        let session = self.session.borrow();
+
+        let candidates = match line[..start].split_whitespace().next() {
+            None => session
+                .commands()
+                .iter()
+                .chain(BUILTINS)
+                .map(|command| command.name.to_string())
+                .filter(|name| name.starts_with(word))
+                .collect(),
+            Some(command) => session.complete(command, word),
+        };
+        Ok((start, candidates))
+    }
+}
+
+#[cfg(feature = "repl")]
+impl Hinter for Completion {
+    type Hint = String;
+}
+
+#[cfg(feature = "repl")]
+impl Highlighter for Completion {}
+
+#[cfg(feature = "repl")]
+impl Validator for Completion {}
+
+#[cfg(feature = "repl")]
+impl Helper for Completion {}
+
+/// Where the history is kept between runs.
+#[cfg(feature = "repl")]
+fn history_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("adventofcode2022").join("repl-history"))
+}
+
+/// Reads commands from the terminal until `quit` or Ctrl-D, with history and
+/// tab completion.
+#[cfg(feature = "repl")]
+pub fn run(session: Box<dyn Session>) -> Result<(), Error> {
+    let readline_error = |e: ReadlineError| match e {
+        ReadlineError::Io(source) => Error::Io { path: None, source },
+        e => Error::Io {
+            path: None,
+            source: io::Error::other(e),
+        },
+    };
+
+    let session = Rc::new(RefCell::new(session));
+    let mut editor: Editor<Completion, FileHistory> = Editor::new().map_err(readline_error)?;
+    editor.set_helper(Some(Completion {
+        session: session.clone(),
+    }));
+    let history = history_path();
+    if let Some(path) = &history {
+        // There's no history on the first run
+        let _ = editor.load_history(path);
+    }
+
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            // Ctrl-C only drops the line being typed
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(readline_error(e)),
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+
+        match eval_line(session.borrow_mut().as_mut(), &line) {
+            Outcome::Print(output) if output.is_empty() => {}
+            Outcome::Print(output) => println!("{}", output),
+            Outcome::Quit => break,
+        }
+    }
+
+    if let Some(path) = &history {
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        // Losing the history isn't worth failing the session for
+        let _ = editor.save_history(path);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A command of each session and what its output starts with on the
+    /// example.
+    const EXAMPLES: &[(u8, &str, &str)] = &[
+        #[cfg(feature = "day7")]
+        (7, "du /a/", "94853"),
+        #[cfg(feature = "day8")]
+        (8, "scenic 2 3", "scenic score 8"),
+        #[cfg(feature = "day10")]
+        (10, "step 10", "cycle 10"),
+        #[cfg(feature = "day11")]
+        (11, "monkey 3", "Monkey 3:"),
+        #[cfg(feature = "day13")]
+        (13, "packet 5", "[9]"),
+    ];
+
+    #[test]
+    fn every_session_answers_on_its_example() {
+        for &(day, line, expected) in EXAMPLES {
+            let input = input::read(day, 1, &input::Source::Example).unwrap();
+            let mut session = session(day, 1, &input).unwrap().unwrap();
+
+            let Outcome::Print(output) = eval_line(session.as_mut(), line) else {
+                panic!("day {} quit on '{}'", day, line);
+            };
+            assert!(output.starts_with(expected), "day {}: {}", day, output);
+            assert!(matches!(eval_line(session.as_mut(), "quit"), Outcome::Quit));
+        }
+        assert_eq!(days().count(), EXAMPLES.len());
+    }
+
+    #[test]
+    #[cfg(feature = "day8")]
+    fn reports_bad_commands_and_arguments() {
+        let input = input::read(8, 1, &input::Source::Example).unwrap();
+        let mut session = session(8, 1, &input).unwrap().unwrap();
+
+        let output = |session: &mut Box<dyn Session>, line| match eval_line(session.as_mut(), line)
+        {
+            Outcome::Print(output) => output,
+            Outcome::Quit => panic!("quit on '{}'", line),
+        };
+        assert!(output(&mut session, "jump").starts_with("unknown command"));
+        assert!(output(&mut session, "scenic 2").starts_with("error: missing"));
+        assert!(output(&mut session, "scenic x 1").starts_with("error: 'x'"));
+        assert!(output(&mut session, "scenic 9 9").starts_with("error:"));
+        assert!(output(&mut session, "help").contains("scenic <x> <y>"));
+    }
+}
@@ -0,0 +1,386 @@
+#[cfg(feature = "solutions")]
+use std::time::Instant;
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{error::Error, memory};
+#[cfg(feature = "solutions")]
+use crate::{solution::Solution, *};
+
+/// A single part of a day, callable through a common entry point.
+pub struct Puzzle {
+    pub day: u8,
+    pub part: u8,
+    run: fn(&str, u8, u8) -> Result<Report, Error>,
+    parse: fn(&str, u8) -> Result<(), Error>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// Peak heap usage of each stage, known when the binary counts its
+    /// allocations with [`memory::CountingAllocator`].
+    pub parse_memory: Option<usize>,
+    pub solve_memory: Option<usize>,
+}
+
+impl Report {
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.solve
+    }
+
+    /// The report as a JSON object, with times in milliseconds. With `mem`,
+    /// the peak heap usage of the stages is included in bytes.
+    pub fn to_json(&self, mem: bool) -> String {
+        #[derive(Serialize)]
+        struct Json<'a> {
+            day: u8,
+            part: u8,
+            answer: &'a str,
+            parse_ms: f64,
+            solve_ms: f64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            parse_bytes: Option<usize>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            solve_bytes: Option<usize>,
+        }
+
+        let json = Json {
+            day: self.day,
+            part: self.part,
+            answer: &self.answer,
+            parse_ms: self.parse.as_secs_f64() * 1000.0,
+            solve_ms: self.solve.as_secs_f64() * 1000.0,
+            parse_bytes: self.parse_memory.filter(|_| mem),
+            solve_bytes: self.solve_memory.filter(|_| mem),
+        };
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+/// Parses the input and solves one part, timing both stages separately and
+/// measuring their memory usage.
+#[cfg(feature = "solutions")]
+fn run<S: Solution>(input: &str, day: u8, part: u8) -> Result<Report, Error> {
+    let stage = memory::Stage::start();
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|source| Error::Parse { day, source })?;
+    let parse = start.elapsed();
+    let parse_memory = stage.map(|stage| stage.peak());
+
+    let stage = memory::Stage::start();
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(&parsed).to_string(),
+        _ => S::part2(&parsed).to_string(),
+    };
+    let solve = start.elapsed();
+    let solve_memory = stage.map(|stage| stage.peak());
+
+    Ok(Report {
+        day,
+        part,
+        answer,
+        parse,
+        solve,
+        parse_memory,
+        solve_memory,
+    })
+}
+
+#[cfg(feature = "solutions")]
+fn parse<S: Solution>(input: &str, day: u8) -> Result<(), Error> {
+    S::parse(input)
+        .map(|_| ())
+        .map_err(|source| Error::Parse { day, source })
+}
+
+pub const PUZZLES: &[Puzzle] = &[
+    #[cfg(feature = "day1")]
+    Puzzle::new::<day1::Day1>(1, 1),
+    #[cfg(feature = "day1")]
+    Puzzle::new::<day1::Day1>(1, 2),
+    #[cfg(feature = "day2")]
+    Puzzle::new::<day2::Day2>(2, 1),
+    #[cfg(feature = "day2")]
+    Puzzle::new::<day2::Day2>(2, 2),
+    #[cfg(feature = "day3")]
+    Puzzle::new::<day3::Day3>(3, 1),
+    #[cfg(feature = "day3")]
+    Puzzle::new::<day3::Day3>(3, 2),
+    #[cfg(feature = "day4")]
+    Puzzle::new::<day4::Day4>(4, 1),
+    #[cfg(feature = "day4")]
+    Puzzle::new::<day4::Day4>(4, 2),
+    #[cfg(feature = "day5")]
+    Puzzle::new::<day5::Day5>(5, 1),
+    #[cfg(feature = "day5")]
+    Puzzle::new::<day5::Day5>(5, 2),
+    #[cfg(feature = "day6")]
+    Puzzle::new::<day6::Day6>(6, 1),
+    #[cfg(feature = "day6")]
+    Puzzle::new::<day6::Day6>(6, 2),
+    #[cfg(feature = "day7")]
+    Puzzle::new::<day7::Day7>(7, 1),
+    #[cfg(feature = "day7")]
+    Puzzle::new::<day7::Day7>(7, 2),
+    #[cfg(feature = "day8")]
+    Puzzle::new::<day8::Day8>(8, 1),
+    #[cfg(feature = "day8")]
+    Puzzle::new::<day8::Day8>(8, 2),
+    #[cfg(feature = "day9")]
+    Puzzle::new::<day9::Day9>(9, 1),
+    #[cfg(feature = "day9")]
+    Puzzle::new::<day9::Day9>(9, 2),
+    #[cfg(feature = "day10")]
+    Puzzle::new::<day10::Day10>(10, 1),
+    #[cfg(feature = "day10")]
+    Puzzle::new::<day10::Day10>(10, 2),
+    #[cfg(feature = "day11")]
+    Puzzle::new::<day11::Day11>(11, 1),
+    #[cfg(feature = "day11")]
+    Puzzle::new::<day11::Day11>(11, 2),
+    #[cfg(feature = "day12")]
+    Puzzle::new::<day12::Day12>(12, 1),
+    #[cfg(feature = "day12")]
+    Puzzle::new::<day12::Day12>(12, 2),
+    #[cfg(feature = "day13")]
+    Puzzle::new::<day13::Day13>(13, 1),
+    #[cfg(feature = "day13")]
+    Puzzle::new::<day13::Day13>(13, 2),
+    #[cfg(feature = "day14")]
+    Puzzle::new::<day14::Day14>(14, 1),
+    #[cfg(feature = "day14")]
+    Puzzle::new::<day14::Day14>(14, 2),
+];
+
+pub fn find(day: u8, part: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.day == day && p.part == part)
+}
+
+pub fn find_day(day: u8) -> Vec<&'static Puzzle> {
+    PUZZLES.iter().filter(|p| p.day == day).collect()
+}
+
+impl Puzzle {
+    #[cfg(feature = "solutions")]
+    const fn new<S: Solution>(day: u8, part: u8) -> Puzzle {
+        Puzzle {
+            day,
+            part,
+            run: run::<S>,
+            parse: parse::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str) -> Result<Report, Error> {
+        (self.run)(input, self.day, self.part)
+    }
+
+    /// Only parses the input, to check it without solving the part.
+    pub fn parse(&self, input: &str) -> Result<(), Error> {
+        (self.parse)(input, self.day)
+    }
+}
+
+/// Stack of the threads solving puzzles, the size of the main thread's on
+/// Linux which the recursive solutions were written against.
+pub const STACK_SIZE: usize = 8 << 20;
+
+/// Runs the puzzles on `jobs` threads, reading each one's input with
+/// `input`. A puzzle panicking becomes an error instead of stopping the
+/// others. The results are in the order of the puzzles.
+pub fn run_parallel<F>(puzzles: &[&Puzzle], jobs: usize, input: F) -> Vec<Result<Report, Error>>
+where
+    F: Fn(&Puzzle) -> Result<String, Error> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let run = |puzzle: &Puzzle| {
+        panic::catch_unwind(AssertUnwindSafe(|| puzzle.run(&input(puzzle)?))).unwrap_or_else(
+            |payload| {
+                Err(Error::Panicked {
+                    message: panic_message(payload.as_ref()),
+                })
+            },
+        )
+    };
+
+    let mut results: Vec<(usize, Result<Report, Error>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, puzzles.len().max(1)))
+            .map(|_| {
+                thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, || {
+                        let mut results = vec![];
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(puzzle) = puzzles.get(i) else {
+                                return results;
+                            };
+                            results.push((i, run(puzzle)));
+                        }
+                    })
+                    .expect("couldn't start a worker thread")
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("panics are caught"))
+            .collect()
+    });
+
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Prints one JSON object per report and line, for tools consuming the
+/// results.
+pub fn print_json(reports: &[Report], mem: bool) {
+    for report in reports {
+        println!("{}", report.to_json(mem));
+    }
+}
+
+/// Prints the reports as a table. Multi-line answers (like the day 10 CRT)
+/// continue on the following rows, aligned with the answer column. With
+/// `mem`, the peak heap usage of the stages is shown after their times.
+pub fn print_table(reports: &[Report], mem: bool) {
+    let answer_width = reports
+        .iter()
+        .flat_map(|r| r.answer.lines())
+        .map(|line| line.chars().count())
+        .fold("Answer".len(), |acc, width| acc.max(width));
+    let columns = if mem { 5 } else { 3 };
+    let rule = "-".repeat(11 + answer_width + columns * 12);
+
+    print!(
+        "Day  Part  {:<answer_width$}  {:>10}  {:>10}  {:>10}",
+        "Answer", "Parse", "Solve", "Total"
+    );
+    if mem {
+        print!("  {:>10}  {:>10}", "Parse mem", "Solve mem");
+    }
+    println!();
+    println!("{}", rule);
+
+    for report in reports {
+        let mut lines = report.answer.lines();
+        let first = lines.next().unwrap_or("");
+
+        print!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10.3?}  {:>10.3?}  {:>10.3?}",
+            report.day,
+            report.part,
+            first,
+            report.parse,
+            report.solve,
+            report.elapsed()
+        );
+        if mem {
+            print!(
+                "  {:>10}  {:>10}",
+                format_memory(report.parse_memory),
+                format_memory(report.solve_memory)
+            );
+        }
+        println!();
+        for line in lines {
+            println!("           {}", line);
+        }
+    }
+
+    let parse: Duration = reports.iter().map(|r| r.parse).sum();
+    let solve: Duration = reports.iter().map(|r| r.solve).sum();
+    println!("{}", rule);
+    println!(
+        "{:>11}{:<answer_width$}  {:>10.3?}  {:>10.3?}  {:>10.3?}",
+        "",
+        "Total",
+        parse,
+        solve,
+        parse + solve
+    );
+}
+
+fn format_memory(bytes: Option<usize>) -> String {
+    bytes.map_or("-".to_string(), memory::format_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(day: u8) -> Result<Report, Error> {
+        Ok(Report {
+            day,
+            part: 1,
+            answer: day.to_string(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+            parse_memory: None,
+            solve_memory: None,
+        })
+    }
+
+    static SLOW: Puzzle = Puzzle {
+        day: 1,
+        part: 1,
+        run: |_, day, _| {
+            thread::sleep(Duration::from_millis(50));
+            answer(day)
+        },
+        parse: |_, _| Ok(()),
+    };
+    static PANICS: Puzzle = Puzzle {
+        day: 2,
+        part: 1,
+        run: |_, _, _| panic!("out of sand"),
+        parse: |_, _| Ok(()),
+    };
+    static FAST: Puzzle = Puzzle {
+        day: 3,
+        part: 1,
+        run: |_, day, _| answer(day),
+        parse: |_, _| Ok(()),
+    };
+
+    #[test]
+    fn keeps_order_and_survives_panics() {
+        let puzzles = [&SLOW, &PANICS, &FAST, &SLOW];
+
+        for jobs in [1, 3, 10] {
+            let results = run_parallel(&puzzles, jobs, |_| Ok(String::new()));
+
+            let answers: Vec<String> = results
+                .iter()
+                .map(|result| match result {
+                    Ok(report) => report.answer.clone(),
+                    Err(e) => e.to_string(),
+                })
+                .collect();
+            assert_eq!(answers, ["1", "panicked: out of sand", "3", "1"]);
+        }
+    }
+}
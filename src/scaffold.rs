@@ -0,0 +1,251 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::error::Error;
+
+/// The module of a new day, `{day}` being replaced with its number.
+const TEMPLATE: &str = r#"use crate::{
+    error::{parse_lines, ParseError},
+    solution::Solution,
+};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Parsed = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, |line| Ok(line.to_string()))
+    }
+
+    fn part1(_lines: &Self::Parsed) -> Self::Part1 {
+        todo!("solve part 1")
+    }
+
+    fn part2(_lines: &Self::Parsed) -> Self::Part2 {
+        todo!("solve part 2")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn part1_example() {
+        let parsed = Day{day}::parse(EXAMPLE).unwrap();
+        // The answer the puzzle gives for the example
+        assert_eq!(Day{day}::part1(&parsed), 0);
+    }
+}
+"#;
+
+/// Where the day is registered in the runner, new days go at the end.
+const PUZZLES_END: &str = "\n];\n";
+
+/// Creates the module of a new day with an empty example and registers it
+/// in the library, the runner and the features of the manifest. Nothing is
+/// written if the day exists. Returns the files created and changed.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let src = root.join("src");
+    let dir = src.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(Error::DayExists { day, path: dir });
+    }
+
+    let lib_path = src.join("lib.rs");
+    let runner_path = src.join("runner.rs");
+    let manifest_path = root.join("Cargo.toml");
+    let lib = register_module(&read(&lib_path)?, day).ok_or_else(|| Error::InvalidFile {
+        path: lib_path.clone(),
+        message: "couldn't find the list of days".to_string(),
+    })?;
+    let runner = register_puzzles(&read(&runner_path)?, day).ok_or_else(|| Error::InvalidFile {
+        path: runner_path.clone(),
+        message: "couldn't find the end of the puzzles".to_string(),
+    })?;
+    let manifest =
+        register_feature(&read(&manifest_path)?, day).ok_or_else(|| Error::InvalidFile {
+            path: manifest_path.clone(),
+            message: "couldn't find the day features".to_string(),
+        })?;
+
+    let module = TEMPLATE.replace("{day}", &day.to_string());
+    let files = [
+        (dir.join("mod.rs"), module),
+        (dir.join("example.txt"), String::new()),
+        (lib_path, lib),
+        (runner_path, runner),
+        (manifest_path, manifest),
+    ];
+
+    fs::create_dir_all(&dir).map_err(|source| Error::Io {
+        path: Some(dir.clone()),
+        source,
+    })?;
+    for (path, contents) in &files {
+        fs::write(path, contents).map_err(|source| Error::Io {
+            path: Some(path.clone()),
+            source,
+        })?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: Some(path.to_path_buf()),
+        source,
+    })
+}
+
+/// Adds `pub mod day{day};` behind its feature to the day modules, keeping
+/// them sorted like rustfmt does.
+fn register_module(lib: &str, day: u8) -> Option<String> {
+    let is_day =
+        |line: &&str| line.starts_with("#[cfg(feature = \"day") || line.starts_with("pub mod day");
+    let lines: Vec<&str> = lib.lines().collect();
+    let days = lines.iter().position(is_day)?;
+    let end = lines[days..]
+        .iter()
+        .position(|line| !is_day(line))
+        .map_or(lines.len(), |len| days + len);
+
+    // The attribute and the module move together
+    let mut modules: Vec<&[&str]> = lines[days..end].chunks(2).collect();
+    let cfg = format!("#[cfg(feature = \"day{}\")]", day);
+    let module = format!("pub mod day{};", day);
+    let new = [cfg.as_str(), module.as_str()];
+    modules.push(&new);
+    modules.sort_by_key(|module| module[1].trim_end_matches(';'));
+
+    let lines: Vec<&str> = lines[..days]
+        .iter()
+        .chain(modules.concat().iter())
+        .chain(&lines[end..])
+        .copied()
+        .collect();
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds both parts of the day behind its feature at the end of the runner's
+/// puzzles.
+fn register_puzzles(runner: &str, day: u8) -> Option<String> {
+    let end = runner.find(PUZZLES_END)?;
+    let puzzles: String = (1..=2)
+        .map(|part| {
+            format!(
+                "\n    #[cfg(feature = \"day{0}\")]\n    Puzzle::new::<day{0}::Day{0}>({0}, {1}),",
+                day, part
+            )
+        })
+        .collect();
+
+    let mut runner = runner.to_string();
+    runner.insert_str(end, &puzzles);
+    Some(runner)
+}
+
+/// Adds the feature of the day, needing only the `solutions` every day
+/// enables, after the last day's and to the `days` enabled by default.
+fn register_feature(manifest: &str, day: u8) -> Option<String> {
+    let is_feature = |line: &str| {
+        line.strip_prefix("day")
+            .and_then(|rest| rest.split_once(" = "))
+            .is_some_and(|(number, _)| number.parse::<u8>().is_ok())
+    };
+
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let last = lines.iter().rposition(|line| is_feature(line))?;
+    lines.insert(last + 1, format!("day{} = [\"solutions\"]", day));
+
+    let group = lines.iter().position(|line| line == "days = [")?;
+    let end = group + lines[group..].iter().position(|line| line == "]")?;
+    lines.insert(end, format!("    \"day{}\",", day));
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn registers_days_in_order() {
+        let lib = "pub mod runner;\n\n#[cfg(feature = \"day1\")]\npub mod day1;\n\
+                   #[cfg(feature = \"day14\")]\npub mod day14;\n\
+                   #[cfg(feature = \"day2\")]\npub mod day2;\n";
+        assert_eq!(
+            register_module(lib, 15).unwrap(),
+            "pub mod runner;\n\n#[cfg(feature = \"day1\")]\npub mod day1;\n\
+             #[cfg(feature = \"day14\")]\npub mod day14;\n\
+             #[cfg(feature = \"day15\")]\npub mod day15;\n\
+             #[cfg(feature = \"day2\")]\npub mod day2;\n"
+        );
+
+        let runner =
+            "pub const PUZZLES: &[Puzzle] = &[\n    Puzzle::new::<day1::Day1>(1, 1),\n];\n";
+        assert_eq!(
+            register_puzzles(runner, 15).unwrap(),
+            "pub const PUZZLES: &[Puzzle] = &[\n    Puzzle::new::<day1::Day1>(1, 1),\n    \
+             #[cfg(feature = \"day15\")]\n    Puzzle::new::<day15::Day15>(15, 1),\n    \
+             #[cfg(feature = \"day15\")]\n    Puzzle::new::<day15::Day15>(15, 2),\n];\n"
+        );
+
+        let manifest =
+            "[features]\ndays = [\n    \"day1\",\n]\nday1 = []\nday2 = [\"dep:regex\"]\n\
+                        color = []\n";
+        assert_eq!(
+            register_feature(manifest, 15).unwrap(),
+            "[features]\ndays = [\n    \"day1\",\n    \"day15\",\n]\nday1 = []\n\
+             day2 = [\"dep:regex\"]\nday15 = [\"solutions\"]\ncolor = []\n"
+        );
+    }
+
+    #[test]
+    fn refuses_to_overwrite_days() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let src = root.join("src");
+        fs::create_dir_all(src.join("day1")).unwrap();
+        fs::write(src.join("day1").join("mod.rs"), "// solved").unwrap();
+        fs::write(
+            src.join("lib.rs"),
+            "#[cfg(feature = \"day1\")]\npub mod day1;\n",
+        )
+        .unwrap();
+        fs::write(src.join("runner.rs"), "const PUZZLES: &[Puzzle] = &[\n];\n").unwrap();
+        let manifest = "[features]\ndays = [\n    \"day1\",\n]\nday1 = []\n";
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+
+        assert!(matches!(
+            scaffold(&root, 1),
+            Err(Error::DayExists { day: 1, .. })
+        ));
+        assert_eq!(
+            fs::read_to_string(src.join("day1").join("mod.rs")).unwrap(),
+            "// solved"
+        );
+
+        scaffold(&root, 2).unwrap();
+        assert!(src.join("day2").join("example.txt").exists());
+        assert_eq!(
+            fs::read_to_string(src.join("lib.rs")).unwrap(),
+            "#[cfg(feature = \"day1\")]\npub mod day1;\n#[cfg(feature = \"day2\")]\npub mod day2;\n"
+        );
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("day2 = [\"solutions\"]"));
+        assert!(scaffold(&root, 2).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
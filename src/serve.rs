@@ -0,0 +1,224 @@
+use std::{
+    io::Read,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use tiny_http::{Header, Method, Request, Response};
+
+use crate::{
+    error::Error,
+    runner::{self, Puzzle, Report},
+};
+
+/// Bounds on what a single request may cost.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Largest input accepted, in bytes.
+    pub max_body: usize,
+    /// How long a part may take to solve before the request fails. The part
+    /// can't be interrupted, it keeps running to the end in the background.
+    pub timeout: Duration,
+    /// Most parts being solved at the same time, counting the ones still
+    /// running after their request timed out. Requests over it fail.
+    pub max_solves: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            max_body: 1 << 20,
+            timeout: Duration::from_secs(10),
+            max_solves: 4,
+        }
+    }
+}
+
+/// Solves a part on its input, catching panics.
+type Solve = dyn Fn(&Puzzle, &str) -> Result<Report, Error> + Send + Sync;
+
+/// Answers `POST /day/{day}/part/{part}` requests, the body being the puzzle
+/// input, with the JSON report of `run --format json`.
+pub struct Server {
+    http: tiny_http::Server,
+    limits: Limits,
+    /// Parts being solved.
+    solving: Arc<AtomicUsize>,
+    solve: Arc<Solve>,
+}
+
+/// One of the [`Limits::max_solves`] parts being solved, given back when
+/// dropped.
+struct Slot(Arc<AtomicUsize>);
+
+impl Slot {
+    fn take(solving: &Arc<AtomicUsize>, max: usize) -> Option<Slot> {
+        solving
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < max).then_some(n + 1)
+            })
+            .ok()
+            .map(|_| Slot(solving.clone()))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl Server {
+    pub fn bind(addr: SocketAddr, limits: Limits) -> Result<Server, Error> {
+        let http = tiny_http::Server::http(addr).map_err(|e| Error::Serve {
+            addr,
+            message: e.to_string(),
+        })?;
+        Ok(Server {
+            http,
+            limits,
+            solving: Arc::new(AtomicUsize::new(0)),
+            solve: Arc::new(|puzzle, input| {
+                runner::run_parallel(&[puzzle], 1, |_| Ok(input.to_string())).remove(0)
+            }),
+        })
+    }
+
+    /// Solves the parts with `solve` instead, for tests to decide how long
+    /// solving takes without depending on the real puzzles.
+    pub fn with_solver<F>(mut self, solve: F) -> Server
+    where
+        F: Fn(&Puzzle, &str) -> Result<Report, Error> + Send + Sync + 'static,
+    {
+        self.solve = Arc::new(solve);
+        self
+    }
+
+    /// The address listened on, with the port picked by the system when
+    /// bound to port 0.
+    pub fn addr(&self) -> SocketAddr {
+        self.http
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on TCP")
+    }
+
+    /// Handles requests forever, `workers` of them at the same time.
+    pub fn run(&self, workers: usize) {
+        thread::scope(|scope| {
+            for _ in 0..workers.max(1) {
+                scope.spawn(|| {
+                    for request in self.http.incoming_requests() {
+                        self.handle(request);
+                    }
+                });
+            }
+        });
+    }
+
+    fn handle(&self, mut request: Request) {
+        let Some((day, part)) = route(request.url()) else {
+            return respond(request, 404, error_json("not found"));
+        };
+        if *request.method() != Method::Post {
+            return respond(request, 405, error_json("only POST is supported"));
+        }
+        let Some(puzzle) = runner::find(day, part) else {
+            let message = format!("no solution for day {} part {}", day, part);
+            return respond(request, 404, error_json(&message));
+        };
+
+        let input = match read_body(&mut request, self.limits.max_body) {
+            Ok(input) => input,
+            Err((status, message)) => return respond(request, status, error_json(&message)),
+        };
+
+        let Some(slot) = Slot::take(&self.solving, self.limits.max_solves) else {
+            let message = "too many parts being solved, try again later";
+            return respond(request, 503, error_json(message));
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let solve = self.solve.clone();
+        thread::spawn(move || {
+            // Held until the part is solved, even once the request gave up
+            let _slot = slot;
+            let _ = sender.send(solve(puzzle, &input));
+        });
+
+        match receiver.recv_timeout(self.limits.timeout) {
+            Ok(Ok(report)) => respond(request, 200, report.to_json(false)),
+            Ok(Err(e @ Error::Parse { .. })) => respond(request, 422, error_json(&e.to_string())),
+            Ok(Err(e)) => respond(request, 500, error_json(&e.to_string())),
+            Err(_) => {
+                let message = format!(
+                    "day {} part {} took longer than {:?}",
+                    day, part, self.limits.timeout
+                );
+                respond(request, 504, error_json(&message))
+            }
+        }
+    }
+}
+
+/// The day and part of `/day/{day}/part/{part}`.
+fn route(url: &str) -> Option<(u8, u8)> {
+    let path = url.split('?').next()?;
+    match path.split('/').collect::<Vec<_>>()[..] {
+        ["", "day", day, "part", part] => Some((day.parse().ok()?, part.parse().ok()?)),
+        _ => None,
+    }
+}
+
+/// Reads the input, refusing bodies over `max` bytes before reading them when
+/// their length is announced.
+fn read_body(request: &mut Request, max: usize) -> Result<String, (u16, String)> {
+    let too_large = || (413, format!("the input is larger than {} bytes", max));
+    if request.body_length().is_some_and(|length| length > max) {
+        return Err(too_large());
+    }
+
+    let mut body = vec![];
+    request
+        .as_reader()
+        .take(max as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| (400, format!("couldn't read the input: {}", e)))?;
+    if body.len() > max {
+        return Err(too_large());
+    }
+
+    String::from_utf8(body).map_err(|_| (400, "the input isn't UTF-8".to_string()))
+}
+
+fn error_json(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+fn respond(request: Request, status: u16, body: String) {
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    // The client hanging up is its problem, not the server's
+    let _ = request.respond(response);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_days_and_parts() {
+        assert_eq!(route("/day/7/part/2"), Some((7, 2)));
+        assert_eq!(route("/day/14/part/1?verbose"), Some((14, 1)));
+        assert_eq!(route("/day/7"), None);
+        assert_eq!(route("/day/x/part/1"), None);
+        assert_eq!(route("/day/7/part/2/"), None);
+    }
+}
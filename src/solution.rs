@@ -0,0 +1,15 @@
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+/// A day of the calendar, split into a parsing stage shared by both parts and
+/// one solving stage per part.
+pub trait Solution {
+    type Parsed;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Self::Part1;
+    fn part2(parsed: &Self::Parsed) -> Self::Part2;
+}
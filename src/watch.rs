@@ -0,0 +1,152 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    color::Colorize,
+    error::Error,
+    input::{self, Source},
+    runner,
+};
+
+/// Polls files for changes of their modification time. A file appearing or
+/// disappearing counts as a change too.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Watcher {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+
+        Watcher { files }
+    }
+
+    /// The files changed since the last call.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        for (path, last_modified) in &mut self.files {
+            let modified = modified(path);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed.push(path.clone());
+            }
+        }
+
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The inputs and sources of a day.
+fn watched_files(day: u8) -> Vec<PathBuf> {
+    let dir = input::day_dir(day);
+
+    vec![
+        input::puzzle_path(day),
+        dir.join("example.txt"),
+        dir.join("example1.txt"),
+        dir.join("example2.txt"),
+        dir.join("mod.rs"),
+    ]
+}
+
+/// Solves both parts of the day against its puzzle and example inputs, then
+/// again every time one of them changes, printing how the answers changed.
+pub fn watch(day: u8, interval: Duration) -> ! {
+    let puzzles = runner::find_day(day);
+    let source_path = input::day_dir(day).join("mod.rs");
+    let mut watcher = Watcher::new(watched_files(day));
+    let mut previous: HashMap<(&str, u8), String> = HashMap::new();
+
+    loop {
+        for (label, source) in [("input", Source::Puzzle), ("example", Source::Example)] {
+            for puzzle in &puzzles {
+                let answer = match input::read(day, puzzle.part, &source) {
+                    Err(Error::MissingInput { .. }) => continue,
+                    // Run with the panics caught, the day being edited often
+                    // has a todo!() left or chokes on a half-written input
+                    Ok(input) => runner::run_parallel(&[*puzzle], 1, |_| Ok(input.clone()))
+                        .remove(0)
+                        .map(|report| report.answer),
+                    Err(e) => Err(e),
+                };
+
+                let line = match answer {
+                    Ok(answer) => {
+                        let status = match previous.insert((label, puzzle.part), answer.clone()) {
+                            None => String::new(),
+                            Some(old) if old == answer => " (unchanged)".dimmed().to_string(),
+                            Some(old) => format!(" (was {})", old).yellow().to_string(),
+                        };
+                        format!("{}{}", answer, status)
+                    }
+                    Err(e) => e.to_string().red().to_string(),
+                };
+                println!("{:<7} part {}: {}", label, puzzle.part, line);
+            }
+        }
+
+        let changed = loop {
+            thread::sleep(interval);
+            let changed = watcher.changed();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        println!();
+        for path in changed {
+            if path == source_path {
+                let message = "changed, rebuild and restart to pick it up";
+                println!("{} {}", path.display(), message.yellow());
+            } else {
+                println!("{} changed", path.display());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs::File, process};
+
+    use super::*;
+
+    #[test]
+    fn detects_modified_created_and_removed_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&path, "1").unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        let file = File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+//! Runs the fetcher against a stand-in for the puzzle server listening on
+//! localhost, so no network is needed.
+
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    process::Command,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use adventofcode2022::{
+    error::Error,
+    fetch::{Fetched, Fetcher},
+};
+
+const INPUT: &str = "1000\n2000\n\n3000\n";
+
+/// A request the server received.
+#[derive(Debug, Clone)]
+struct Request {
+    path: String,
+    cookie: Option<String>,
+    at: Instant,
+}
+
+/// Serves [`INPUT`] as the day 1 input to the `session=token` cookie and
+/// answers 404 to anything else.
+struct Server {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Server {
+    fn start() -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2022", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let received = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line.split(' ').nth(1).unwrap_or("").to_string();
+
+                let mut cookie = None;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("cookie") {
+                            cookie = Some(value.trim().to_string());
+                        }
+                    }
+                }
+
+                let (status, body) = match (path.as_str(), cookie.as_deref()) {
+                    ("/2022/day/1/input", Some("session=token")) => ("200 OK", INPUT),
+                    ("/2022/day/1/input", _) => ("400 Bad Request", "log in"),
+                    _ => ("404 Not Found", "not found"),
+                };
+                received.lock().unwrap().push(Request {
+                    path,
+                    cookie,
+                    at: Instant::now(),
+                });
+
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        Server { url, requests }
+    }
+
+    fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// An empty directory unique to the test.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn fetcher(server: &Server, cache_dir: PathBuf) -> Fetcher {
+    Fetcher {
+        url: server.url.clone(),
+        session: "token".to_string(),
+        cache_dir,
+        min_interval: Duration::from_millis(300),
+    }
+}
+
+#[test]
+fn downloads_once_then_uses_the_cache() {
+    let server = Server::start();
+    let dir = temp_dir("cache");
+    let fetcher = fetcher(&server, dir.clone());
+
+    let path = dir.join("day1.txt");
+    assert_eq!(
+        fetcher.fetch(1, false).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+    assert_eq!(fetcher.fetch(1, false).unwrap(), Fetched::Cached(path));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=token"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn only_overwrites_with_force_and_waits_between_requests() {
+    let server = Server::start();
+    let dir = temp_dir("force");
+    let fetcher = fetcher(&server, dir.clone());
+
+    fs::write(dir.join("day1.txt"), "edited").unwrap();
+    fetcher.fetch(1, false).unwrap();
+    assert_eq!(fs::read_to_string(dir.join("day1.txt")).unwrap(), "edited");
+
+    fetcher.fetch(1, true).unwrap();
+    fetcher.fetch(1, true).unwrap();
+    assert_eq!(fs::read_to_string(dir.join("day1.txt")).unwrap(), INPUT);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    // The server sees requests a little after they're made, which can
+    // shorten the interval it measures
+    let slack = Duration::from_millis(50);
+    assert!(requests[1].at - requests[0].at >= fetcher.min_interval - slack);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reports_refused_requests_without_caching_them() {
+    let server = Server::start();
+    let dir = temp_dir("errors");
+    let mut fetcher = fetcher(&server, dir.clone());
+    fetcher.min_interval = Duration::ZERO;
+
+    let error = fetcher.fetch(2, false).unwrap_err();
+    assert!(matches!(error, Error::Fetch { day: 2, .. }), "{}", error);
+    assert!(error.to_string().contains("404"));
+    assert!(!dir.join("day2.txt").exists());
+
+    fetcher.session = "expired".to_string();
+    let error = fetcher.fetch(1, false).unwrap_err();
+    assert!(error.to_string().contains("session token"), "{}", error);
+    assert!(!dir.join("day1.txt").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fetch_command_reads_its_configuration_from_the_environment() {
+    let server = Server::start();
+    let dir = temp_dir("command");
+    let aoc = || {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+        command
+            .args(["fetch", "1"])
+            .env("AOC_URL", &server.url)
+            .env("AOC_CACHE_DIR", dir.join("cache"))
+            .env("XDG_CONFIG_HOME", dir.join("config"))
+            .env_remove("AOC_SESSION");
+        command
+    };
+
+    let output = aoc().output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no session token"));
+
+    let output = aoc().env("AOC_SESSION", "token").output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("cache").join("day1.txt")).unwrap(),
+        INPUT
+    );
+
+    fs::create_dir_all(dir.join("config").join("adventofcode2022")).unwrap();
+    fs::write(
+        dir.join("config").join("adventofcode2022").join("session"),
+        "token\n",
+    )
+    .unwrap();
+    let output = aoc().output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already cached"));
+    assert_eq!(server.requests().len(), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
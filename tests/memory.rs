@@ -0,0 +1,31 @@
+//! Installs the counting allocator, which only the binary does otherwise,
+//! to check what it reports.
+
+use adventofcode2022::{
+    input::{self, Source},
+    memory::{CountingAllocator, Stage},
+    runner,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn measures_the_peak_of_each_stage() {
+    let stage = Stage::start().unwrap();
+    let buffer = vec![0u8; 1 << 20];
+    drop(buffer);
+    let small = vec![0u8; 16];
+    assert!(stage.peak() >= 1 << 20);
+    assert!(stage.peak() < 2 << 20);
+
+    let stage = Stage::start().unwrap();
+    assert!(stage.peak() < 1 << 20);
+    drop(small);
+
+    let puzzle = runner::find(7, 1).unwrap();
+    let example = input::read(7, 1, &Source::Example).unwrap();
+    let report = puzzle.run(&example).unwrap();
+    assert!(report.parse_memory.unwrap() > 0);
+    assert!(report.solve_memory.is_some());
+}
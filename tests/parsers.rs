@@ -0,0 +1,177 @@
+//! Feeds every parser random and malformed inputs, which they have to reject
+//! with an error instead of panicking, and checks that the inputs with a
+//! `Display` survive a round trip through it.
+
+use std::collections::HashSet;
+
+use adventofcode2022::{
+    day11::Day11,
+    day13::{Day13, Input},
+    day14::Day14,
+    generate,
+    geometry::{Grid, Point},
+    input::{self, Source},
+    runner::{self, Puzzle},
+    solution::Solution,
+};
+use proptest::{collection::vec, prelude::*, sample::select};
+
+/// One parser per day, both parts share it.
+fn parsers() -> impl Iterator<Item = &'static Puzzle> {
+    runner::PUZZLES.iter().filter(|puzzle| puzzle.part == 1)
+}
+
+fn parse_all(input: &str) {
+    for puzzle in parsers() {
+        let _ = puzzle.parse(input);
+    }
+}
+
+/// Characters found in the puzzle inputs and a few found in none of them.
+fn puzzle_char() -> impl Strategy<Value = char> {
+    let chars: Vec<char> = "[]0123456789 ,-> $/.=*+:\nabcdefxyzSEADLRUX\té€🎄"
+        .chars()
+        .collect();
+    prop_oneof![4 => select(chars), 1 => any::<char>()]
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Insert(usize, char),
+    Remove(usize),
+    Replace(usize, char),
+    Truncate(usize),
+}
+
+impl Edit {
+    fn apply(&self, text: &mut Vec<char>) {
+        let at = |i: usize, len: usize| i % (len + 1);
+        match *self {
+            Edit::Insert(i, c) => text.insert(at(i, text.len()), c),
+            Edit::Remove(i) if !text.is_empty() => {
+                text.remove(i % text.len());
+            }
+            Edit::Replace(i, c) if !text.is_empty() => {
+                let i = i % text.len();
+                text[i] = c;
+            }
+            Edit::Truncate(i) => text.truncate(at(i, text.len())),
+            _ => {}
+        }
+    }
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        4 => (any::<usize>(), puzzle_char()).prop_map(|(i, c)| Edit::Insert(i, c)),
+        4 => any::<usize>().prop_map(Edit::Remove),
+        4 => (any::<usize>(), puzzle_char()).prop_map(|(i, c)| Edit::Replace(i, c)),
+        1 => any::<usize>().prop_map(Edit::Truncate),
+    ]
+}
+
+fn packet() -> impl Strategy<Value = Input> {
+    let value = any::<u32>().prop_map(Input::Value);
+    let nested = value.prop_recursive(4, 64, 6, |inner| vec(inner, 0..6).prop_map(Input::List));
+
+    nested.prop_map(|packet| match packet {
+        Input::List(_) => packet,
+        value => Input::List(vec![value]),
+    })
+}
+
+/// A rock path below the sand source and the rocks it's made of.
+fn rock_path() -> impl Strategy<Value = (String, Vec<(i32, i32)>)> {
+    let start = (480i32..520, 1i32..30);
+    let segments = vec((1..8, any::<bool>()), 1..5);
+
+    (start, segments).prop_map(|((mut x, mut y), segments)| {
+        let mut points = vec![format!("{},{}", x, y)];
+        let mut rocks = vec![(x, y)];
+        for (i, (len, forward)) in segments.into_iter().enumerate() {
+            let sign = if forward { 1 } else { -1 };
+            for _ in 0..len {
+                if i % 2 == 0 {
+                    x += sign;
+                } else {
+                    y = (y + sign).max(1);
+                }
+                rocks.push((x, y));
+            }
+            points.push(format!("{},{}", x, y));
+        }
+
+        (points.join(" -> "), rocks)
+    })
+}
+
+#[test]
+fn parsers_accept_their_examples() {
+    for puzzle in parsers() {
+        let example = input::read(puzzle.day, puzzle.part, &Source::Example).unwrap();
+        puzzle.parse(&example).unwrap();
+    }
+}
+
+proptest! {
+    #[test]
+    fn parsers_never_panic_on_arbitrary_text(input in any::<String>()) {
+        parse_all(&input);
+    }
+
+    #[test]
+    fn parsers_never_panic_on_puzzle_like_text(input in vec(puzzle_char(), 0..400)) {
+        parse_all(&input.into_iter().collect::<String>());
+    }
+
+    #[test]
+    fn parsers_never_panic_on_edited_examples(
+        day in select(parsers().map(|puzzle| puzzle.day).collect::<Vec<u8>>()),
+        edits in vec(edit(), 1..10),
+    ) {
+        let puzzle = runner::find(day, 1).unwrap();
+        let example = input::read(day, 1, &Source::Example).unwrap();
+
+        let mut text: Vec<char> = example.chars().collect();
+        for edit in &edits {
+            edit.apply(&mut text);
+        }
+        let _ = puzzle.parse(&text.into_iter().collect::<String>());
+    }
+
+    #[test]
+    fn packets_round_trip(left in packet(), right in packet()) {
+        let text = format!("{}\n{}\n", left, right);
+
+        prop_assert_eq!(Day13::parse(&text).unwrap(), vec![(left, right)]);
+    }
+
+    #[test]
+    fn monkeys_round_trip(seed in any::<u64>(), size in 1usize..8) {
+        let text = generate::generate(11, size, seed).unwrap();
+        let monkeys = Day11::parse(&text).unwrap();
+
+        let displayed: Vec<String> = monkeys.iter().map(|monkey| monkey.to_string()).collect();
+        let reparsed = Day11::parse(&displayed.join("\n")).unwrap();
+        let redisplayed: Vec<String> = reparsed.iter().map(|monkey| monkey.to_string()).collect();
+        prop_assert_eq!(displayed, redisplayed);
+    }
+
+    #[test]
+    fn rock_maps_round_trip(paths in vec(rock_path(), 1..8)) {
+        let text: Vec<&str> = paths.iter().map(|(path, _)| path.as_str()).collect();
+        let rocks: HashSet<(i32, i32)> = paths.iter().flat_map(|(_, rocks)| rocks.clone()).collect();
+
+        let map = Day14::parse(&text.join("\n")).unwrap();
+        let grid: Grid<char> = map.to_string().parse().unwrap();
+
+        let left = rocks.iter().map(|&(x, _)| x).min().unwrap().min(500);
+        let drawn: HashSet<(i32, i32)> = grid
+            .iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|(point, _)| (point.x as i32 + left, point.y as i32))
+            .collect();
+        prop_assert_eq!(drawn, rocks);
+        prop_assert_eq!(grid[Point::new((500 - left) as usize, 0)], '+');
+    }
+}
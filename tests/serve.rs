@@ -0,0 +1,114 @@
+//! Sends requests to a server listening on localhost.
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use adventofcode2022::{
+    input::{self, Source},
+    serve::{Limits, Server},
+};
+use serde_json::Value;
+
+/// Starts a server on a free port, left running until the tests end.
+fn start(limits: Limits) -> String {
+    serve(Server::bind(SocketAddr::from(([127, 0, 0, 1], 0)), limits).unwrap())
+}
+
+fn serve(server: Server) -> String {
+    let url = format!("http://{}", server.addr());
+    thread::spawn(move || server.run(2));
+    url
+}
+
+fn request(method: &str, url: &str, body: &str) -> (u16, Value) {
+    let response = match ureq::request(method, url).send_string(body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => panic!("{}", e),
+    };
+    let status = response.status();
+    (
+        status,
+        serde_json::from_str(&response.into_string().unwrap()).unwrap(),
+    )
+}
+
+#[test]
+fn answers_parts_with_their_reports() {
+    let url = start(Limits::default());
+    let example = input::read(1, 2, &Source::Example).unwrap();
+
+    let (status, json) = request("POST", &format!("{}/day/1/part/2", url), &example);
+    assert_eq!(status, 200);
+    assert_eq!(json["day"], 1);
+    assert_eq!(json["part"], 2);
+    assert_eq!(json["answer"], "45000");
+    assert!(json["parse_ms"].is_number());
+    assert!(json["solve_ms"].is_number());
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let url = start(Limits {
+        max_body: 64,
+        ..Limits::default()
+    });
+
+    let (status, json) = request("POST", &format!("{}/day/1/part/1", url), "1\nx\n");
+    assert_eq!(status, 422);
+    assert!(
+        json["error"].as_str().unwrap().contains("day 1"),
+        "{}",
+        json
+    );
+
+    let (status, _) = request("POST", &format!("{}/day/1/part/1", url), &"1\n".repeat(40));
+    assert_eq!(status, 413);
+
+    let (status, _) = request("POST", &format!("{}/day/25/part/1", url), "");
+    assert_eq!(status, 404);
+
+    let (status, _) = request("POST", &format!("{}/answers", url), "");
+    assert_eq!(status, 404);
+
+    let (status, _) = request("GET", &format!("{}/day/1/part/1", url), "");
+    assert_eq!(status, 405);
+}
+
+#[test]
+fn fails_requests_taking_too_long() {
+    let limits = Limits {
+        timeout: Duration::from_millis(1),
+        max_solves: 1,
+        ..Limits::default()
+    };
+    // Solving waits for the test to let go of the gate
+    let gate = Arc::new(Mutex::new(()));
+    let held = gate.lock().unwrap();
+    let server = Server::bind(SocketAddr::from(([127, 0, 0, 1], 0)), limits)
+        .unwrap()
+        .with_solver({
+            let gate = gate.clone();
+            move |puzzle, input| {
+                let _open = gate.lock();
+                puzzle.run(input)
+            }
+        });
+    let url = serve(server);
+    let example = input::read(1, 1, &Source::Example).unwrap();
+
+    let (status, json) = request("POST", &format!("{}/day/1/part/1", url), &example);
+    assert_eq!(status, 504);
+    assert!(json["error"].as_str().unwrap().contains("longer than"));
+
+    // The part that timed out is still being solved
+    let (status, json) = request("POST", &format!("{}/day/1/part/2", url), &example);
+    assert_eq!(status, 503);
+    assert!(json["error"].as_str().unwrap().contains("too many"));
+
+    drop(held);
+}